@@ -1,15 +1,25 @@
-use bevy::{prelude::*, reflect::TypeInfo};
-use bevy_dioxus::{colors::*, prelude::*};
+use bevy::{
+    asset::ReflectHandle,
+    ecs::component::ComponentInfo,
+    prelude::*,
+    reflect::{
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        ReflectRef, TypeRegistry,
+    },
+};
+use bevy_dioxus::prelude::{Button, *};
 use bevy_mod_picking::DefaultPickingPlugins;
+use serde::de::DeserializeSeed;
+use std::{any::TypeId, borrow::Cow};
 
 fn main() {
     App::new()
         .add_plugins((DefaultPlugins, DioxusUiPlugin, DefaultPickingPlugins))
+        .init_resource::<StringEdit>()
+        .add_systems(Update, type_into_string_edit)
+        .add_systems(Startup, register_numeric_field_ranges)
         .add_systems(Startup, |mut commands: Commands| {
-            commands.spawn(DioxusUiBundle {
-                dioxus_ui_root: DioxusUiRoot(Editor),
-                node_bundle: NodeBundle::default(),
-            });
+            commands.spawn(DioxusUiBundle::new(Editor));
             commands.spawn((Camera2dBundle::default(), Name::new("Camera")));
         })
         .run();
@@ -17,26 +27,30 @@ fn main() {
 
 #[component]
 fn Editor(cx: Scope) -> Element {
-    // TODO: When selected entity is despawned, need to reset this to None
     let selected_entity = use_state_sendable(cx, || Option::<Entity>::None);
+    use_context_provider(cx, || selected_entity.clone());
+    let copied_component = use_state_sendable(cx, || Option::<(TypeId, String)>::None);
+    use_context_provider(cx, || copied_component.clone());
 
     render! {
         node {
             width: "100vw",
             height: "100vh",
             justify_content: "space_between",
-            SceneTree { selected_entity: selected_entity }
-            EntityInspector { selected_entity: selected_entity }
+            SceneTree {}
+            EntityInspector {}
         }
     }
 }
 
 #[component]
-fn SceneTree<'a>(cx: Scope, selected_entity: &'a UseStateSendable<Option<Entity>>) -> Element {
-    let entities = use_query_filtered::<(Entity, DebugName), Without<Node>>(cx);
-    let entities = entities.query();
-    let mut entities = entities.into_iter().collect::<Vec<_>>();
-    entities.sort_by_key(|(entity, _)| *entity);
+fn SceneTree(cx: Scope) -> Element {
+    let selected_entity = use_context_sendable::<Option<Entity>>(cx).unwrap();
+    // Named entities by name, after unnamed ones by id
+    let entities =
+        use_query_sorted::<Option<&Name>, (Without<Node>, Without<Parent>), _>(cx, |name| {
+            name.map(|name| name.to_string())
+        });
 
     let system_scheduler = use_system_scheduler(cx);
 
@@ -48,24 +62,8 @@ fn SceneTree<'a>(cx: Scope, selected_entity: &'a UseStateSendable<Option<Entity>
                 rsx! { "No entities exist" }
             } else {
                 rsx! {
-                    for (entity, name) in entities {
-                        Button {
-                            onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
-                                if Some(entity) == *selected_entity.read() {
-                                    selected_entity.write(None);
-                                } else {
-                                    selected_entity.write(Some(entity));
-                                }
-                                event.stop_propagation();
-                            },
-                            base_color: if Some(entity) == *selected_entity.read() { Some(VIOLET_700) } else { None },
-                            click_color: if Some(entity) == *selected_entity.read() { Some(VIOLET_400) } else { None },
-                            hover_color: if Some(entity) == *selected_entity.read() { Some(VIOLET_500) } else { None },
-                            match name.name {
-                                Some(name) => format!("{name}"),
-                                _ => format!("Entity ({:?})", name.entity)
-                            }
-                        }
+                    for (entity, _) in entities {
+                        SceneTreeEntity { key: "{entity:?}", entity: entity, depth: 0 }
                     }
                 }
             }
@@ -87,30 +85,113 @@ fn SceneTree<'a>(cx: Scope, selected_entity: &'a UseStateSendable<Option<Entity>
 }
 
 #[component]
-fn EntityInspector<'a>(
-    cx: Scope,
-    selected_entity: &'a UseStateSendable<Option<Entity>>,
-) -> Element {
+fn SceneTreeEntity(cx: Scope, entity: Entity, depth: usize) -> Element {
+    let selected_entity = use_context_sendable::<Option<Entity>>(cx).unwrap();
+    let names = use_query_filtered::<DebugName, Without<Node>>(cx);
+    let children = use_children(cx, *entity);
+    let system_scheduler = use_system_scheduler(cx);
+
+    let entity = *entity;
+    let names = names.query();
+    let Ok(name) = names.get(entity) else {
+        return None;
+    };
+    let is_selected = Some(entity) == *selected_entity.read();
+    let indent = depth * 12;
+    let entity_bits = entity.to_bits();
+
+    render! {
+        node {
+            justify_content: "space_between",
+            padding_left: "{indent}",
+            drag_data: "{entity_bits}",
+            ondrop: move |event: DioxusEvent<PointerDrop>| {
+                let Some(dragged) = event.data.data.as_ref().and_then(|data| data.parse().ok()) else {
+                    return;
+                };
+                system_scheduler.schedule(move |world: &mut World| reparent(Entity::from_bits(dragged), entity, world));
+                event.stop_propagation();
+            },
+            Button {
+                onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
+                    if is_selected {
+                        selected_entity.write(None);
+                    } else {
+                        selected_entity.write(Some(entity));
+                    }
+                    event.stop_propagation();
+                },
+                base_color: if is_selected { Some("theme.accent") } else { None },
+                click_color: if is_selected { Some("theme.accent_click") } else { None },
+                hover_color: if is_selected { Some("theme.accent_hover") } else { None },
+                match name.name {
+                    Some(name) => format!("{name}"),
+                    _ => format!("Entity ({:?})", name.entity)
+                }
+            }
+            Button {
+                onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
+                    system_scheduler.despawn_recursive(entity);
+                    if is_selected {
+                        selected_entity.write(None);
+                    }
+                    event.stop_propagation();
+                },
+                "Delete"
+            }
+        }
+        for child in children {
+            SceneTreeEntity { key: "{child:?}", entity: child, depth: depth + 1 }
+        }
+    }
+}
+
+/// Makes `child` a child of `parent`, unless that would put `child` inside itself.
+fn reparent(child: Entity, parent: Entity, world: &mut World) {
+    if world.get_entity(parent).is_none() {
+        return;
+    }
+    let mut ancestor = Some(parent);
+    while let Some(entity) = ancestor {
+        if entity == child {
+            return;
+        }
+        ancestor = world.get::<Parent>(entity).map(Parent::get);
+    }
+    if let Some(mut child) = world.get_entity_mut(child) {
+        child.set_parent(parent);
+    }
+}
+
+#[component]
+fn EntityInspector(cx: Scope) -> Element {
+    let selected_entity = use_context_sendable::<Option<Entity>>(cx).unwrap();
     let world = use_world(cx);
-    let type_registry = use_resource::<AppTypeRegistry>(cx).read();
+    let type_registry = use_resource_expect::<AppTypeRegistry>(cx).read();
+    let entity = *selected_entity.read();
     let components = selected_entity
         .read()
         .map(|selected_entity| {
-            let entity_ref = world.get_entity(selected_entity).unwrap();
+            let Some(entity_ref) = world.get_entity(selected_entity) else {
+                return Vec::new();
+            };
             let mut components = entity_ref
                 .archetype()
                 .components()
                 .map(|component_id| {
                     let component_info = world.components().get_info(component_id).unwrap();
-                    let type_info = component_info
-                        .type_id()
-                        .and_then(|type_id| type_registry.get_type_info(type_id));
+                    let value = component_info.type_id().and_then(|type_id| {
+                        let reflect_component =
+                            type_registry.get_type_data::<ReflectComponent>(type_id)?;
+                        Some((type_id, reflect_component.reflect(entity_ref)?))
+                    });
                     let (_, name) = component_info.name().rsplit_once("::").unwrap();
                     let (crate_name, _) = component_info.name().split_once("::").unwrap();
-                    (name, crate_name, type_info)
+                    let layout = describe_layout(component_info);
+                    (name, crate_name, value, layout)
                 })
                 .collect::<Vec<_>>();
-            components.sort_by_key(|(name, _, _)| *name);
+            components.sort_by_key(|(name, ..)| *name);
             components
         })
         .unwrap_or_default();
@@ -119,7 +200,7 @@ fn EntityInspector<'a>(
         if selected_entity.read().is_none() {
             rsx! {
                 node {
-                    margin: "8",
+                    margin: "theme.md",
                     "Select an entity to view its components"
                 }
             }
@@ -127,22 +208,77 @@ fn EntityInspector<'a>(
             rsx! {
                 node {
                     flex_direction: "column",
-                    margin: "8",
-                    text { text: "Entity Inspector", text_size: "24" }
-                    for (name, crate_name, type_info) in components {
+                    margin: "theme.md",
+                    text { text: "Entity Inspector", text_size: "24", text_transform: "uppercase", role: "heading" }
+                    for (name, crate_name, value, layout) in components {
                         node {
                             flex_direction: "column",
                             margin_bottom: "6",
-                            node {
-                                column_gap: "6",
-                                align_items: "baseline",
-                                text { text: name, text_size: "18" }
-                                text { text: crate_name, text_size: "14", text_color: NEUTRAL_400 }
+                            Collapsible {
+                                title: name,
+                                text { text: crate_name, text_size: "14", text_color: "theme.text_muted" }
+                                match (entity, value) {
+                                    (Some(entity), Some((type_id, value))) => rsx! {
+                                        ComponentClipboard { entity: entity, type_id: type_id }
+                                        ComponentInspector { entity: entity, type_id: type_id, value: value }
+                                    },
+                                    _ => rsx! { "(no reflection data) {layout}" },
+                                }
                             }
-                            if let Some(type_info) = type_info {
-                                rsx! { ComponentInspector { type_info: type_info } }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Describes a component from its `ComponentInfo` alone, for components that aren't registered
+/// for reflection.
+fn describe_layout(component_info: &ComponentInfo) -> String {
+    let layout = component_info.layout();
+    format!("{} bytes, aligned to {}", layout.size(), layout.align())
+}
+
+/// Copy and paste buttons that move a component's value between entities as RON.
+#[component]
+fn ComponentClipboard(cx: Scope, entity: Entity, type_id: TypeId) -> Element {
+    let copied_component = use_context_sendable::<Option<(TypeId, String)>>(cx).unwrap();
+    let system_scheduler = use_system_scheduler(cx);
+
+    let (entity, type_id) = (*entity, *type_id);
+    let copied_ron = match &*copied_component.read() {
+        Some((copied_type_id, ron)) if *copied_type_id == type_id => Some(ron.clone()),
+        _ => None,
+    };
+
+    render! {
+        node {
+            column_gap: "6",
+            margin_bottom: "4",
+            Button {
+                onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
+                    system_scheduler.schedule({
+                        let copied_component = (*copied_component).clone();
+                        move |world: &mut World| {
+                            if let Some(ron) = copy_component(entity, type_id, world) {
+                                copied_component.write(Some((type_id, ron)));
                             }
                         }
+                    });
+                    event.stop_propagation();
+                },
+                text { text: "Copy", text_size: "14" }
+            }
+            if let Some(ron) = copied_ron {
+                rsx! {
+                    Button {
+                        onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
+                            let ron = ron.clone();
+                            system_scheduler.schedule(move |world: &mut World| paste_component(entity, type_id, &ron, world));
+                            event.stop_propagation();
+                        },
+                        text { text: "Paste", text_size: "14" }
                     }
                 }
             }
@@ -150,57 +286,555 @@ fn EntityInspector<'a>(
     }
 }
 
+/// Serializes an entity's component to RON through its reflection data.
+fn copy_component(entity: Entity, type_id: TypeId, world: &World) -> Option<String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_component = type_registry.get_type_data::<ReflectComponent>(type_id)?;
+    let value = reflect_component.reflect(world.get_entity(entity)?)?;
+    ron::to_string(&TypedReflectSerializer::new(value, &type_registry))
+        .map_err(|error| {
+            warn!(
+                "Can't copy `{}`, it may be missing `#[reflect(Serialize)]`: {error}",
+                value.reflect_type_path()
+            );
+        })
+        .ok()
+}
+
+/// Deserializes a component copied with [`copy_component`] and applies it to `entity`.
+fn paste_component(entity: Entity, type_id: TypeId, ron: &str, world: &mut World) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let (Some(registration), Some(reflect_component)) = (
+        type_registry.get(type_id),
+        type_registry.get_type_data::<ReflectComponent>(type_id),
+    ) else {
+        return;
+    };
+    let type_path = registration.type_info().type_path();
+
+    let mut ron_deserializer = match ron::Deserializer::from_str(ron) {
+        Ok(ron_deserializer) => ron_deserializer,
+        Err(error) => {
+            warn!("Can't paste `{type_path}`: {error}");
+            return;
+        }
+    };
+    match TypedReflectDeserializer::new(registration, &type_registry)
+        .deserialize(&mut ron_deserializer)
+    {
+        Ok(value) => {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                reflect_component.apply(&mut entity, &*value);
+            }
+        }
+        Err(error) => {
+            warn!(
+                "Can't paste `{type_path}`, it may be missing `#[reflect(Deserialize)]`: {error}"
+            );
+        }
+    }
+}
+
 #[component]
-fn ComponentInspector<'a>(cx: Scope, type_info: &'a TypeInfo) -> Element {
+fn ComponentInspector<'a>(
+    cx: Scope,
+    entity: Entity,
+    type_id: TypeId,
+    value: &'a dyn Reflect,
+) -> Element {
+    let type_registry = use_resource_expect::<AppTypeRegistry>(cx).read();
+    let asset_server = use_resource_expect::<AssetServer>(cx);
+
+    // Components can be references themselves, e.g. `Handle<Image>`
+    if let Some(summary) = describe_reference(*value, &type_registry, asset_server) {
+        return render! { "{summary}" };
+    }
     render! {
-        match type_info {
-            TypeInfo::Struct(info) => rsx! {
-                for field in info.iter() {
-                    format!("{}: {}", field.name(), field.type_path())
+        for (name, path, field) in reflect_fields(*value) {
+            FieldInspector {
+                key: "{name}",
+                name: name,
+                value: field,
+                target: path.map(|path| FieldTarget { entity: *entity, type_id: *type_id, path }),
+            }
+        }
+    }
+}
+
+/// Where a field lives, to write to it: a component of an entity and the field's reflect path
+/// within it.
+#[derive(Clone, PartialEq)]
+struct FieldTarget {
+    entity: Entity,
+    type_id: TypeId,
+    path: String,
+}
+
+impl FieldTarget {
+    /// The target of a nested field, if it's reachable by a reflect path.
+    fn field(&self, path: Option<String>) -> Option<Self> {
+        Some(Self {
+            path: format!("{}{}", self.path, path?),
+            ..self.clone()
+        })
+    }
+}
+
+/// One field, collapsed by default so deeply nested values only render as far as they're expanded.
+#[component]
+fn FieldInspector<'a>(
+    cx: Scope,
+    name: String,
+    value: &'a dyn Reflect,
+    target: Option<FieldTarget>,
+) -> Element {
+    let expanded = use_state(cx, || false);
+    let type_registry = use_resource_expect::<AppTypeRegistry>(cx).read();
+    let asset_server = use_resource_expect::<AssetServer>(cx);
+    let (summary, fields) = match describe_reference(*value, &type_registry, asset_server) {
+        Some(summary) => (summary, Vec::new()),
+        None => (reflect_summary(*value), reflect_fields(*value)),
+    };
+    let expandable = !fields.is_empty();
+    let toggle = if **expanded { "-" } else { "+" };
+
+    if let (true, Some(target)) = (value.is::<f32>(), target) {
+        return render! { F32Field { name: name.clone(), target: target.clone() } };
+    }
+    if let (Some(text), Some(target)) = (reflect_str(*value), target) {
+        return render! {
+            StringField { name: name.clone(), text: text.to_owned(), target: target.clone() }
+        };
+    }
+    render! {
+        node {
+            flex_direction: "column",
+            node {
+                column_gap: "6",
+                onclick: move |_| if expandable { expanded.set(!**expanded) },
+                if expandable {
+                    rsx! { text { text: toggle, text_color: "theme.text_muted" } }
                 }
+                text { text: "{name}: {summary}" }
+            }
+            if **expanded {
+                rsx! {
+                    node {
+                        flex_direction: "column",
+                        padding_left: "theme.md",
+                        for (name, path, field) in fields {
+                            FieldInspector {
+                                key: "{name}",
+                                name: name,
+                                value: field,
+                                target: target.as_ref().and_then(|target| target.field(path)),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An `f32` field that scrolling over adjusts by `step`, or ten times that while holding shift,
+/// and that clicking edits like a [`StringField`]. Fields with a [`NumericFieldRange`] are clamped
+/// to it and shown at its precision.
+#[component]
+fn F32Field(cx: Scope, name: String, target: FieldTarget, step: Option<f32>) -> Element {
+    let world = use_world(cx);
+    let type_registry = use_resource_expect::<AppTypeRegistry>(cx).read();
+    let string_edit = use_resource_expect::<StringEdit>(cx);
+    let system_scheduler = use_system_scheduler(cx);
+    let value = read_field::<f32>(target, world).unwrap_or_default();
+    let value = match numeric_field_range(target, &type_registry) {
+        Some(range) => range.format(value),
+        None => value.to_string(),
+    };
+    let step = step.unwrap_or(0.1);
+    let editing = string_edit.target.as_ref() == Some(target);
+    let shown_value = if editing { &string_edit.text } else { &value };
+    let cursor = if editing { "|" } else { "" };
+    let edit_text = value.clone();
+
+    render! {
+        node {
+            padding_left: "14",
+            onwheel: move |event: DioxusEvent<PointerWheel>| {
+                // Some platforms turn vertical scrolling sideways while shift is held
+                let delta = event.data.delta.x + event.data.delta.y;
+                if delta == 0.0 {
+                    return;
+                }
+                let target = target.clone();
+                system_scheduler.schedule(move |world: &mut World| {
+                    let keyboard = world.resource::<Input<KeyCode>>();
+                    let step = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+                        step * 10.0
+                    } else {
+                        step
+                    };
+                    let Some(value) = read_field::<f32>(&target, world) else {
+                        return;
+                    };
+                    write_f32_field(&target, value + delta.signum() * step, world);
+                });
             },
-            TypeInfo::TupleStruct(_) => rsx! { "TODO" },
-            TypeInfo::Tuple(_) => rsx! { "TODO" },
-            TypeInfo::List(_) => rsx! { "TODO" },
-            TypeInfo::Array(_) => rsx! { "TODO" },
-            TypeInfo::Map(_) => rsx! { "TODO" },
-            TypeInfo::Enum(_) => rsx! { "TODO" },
-            TypeInfo::Value(_) => rsx! { "TODO" },
-        }
-    }
-}
-
-#[allow(non_snake_case)]
-fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element<'a> {
-    let clicked = use_state(cx, || false);
-    let hovered = use_state(cx, || false);
-    let background_color = if **clicked {
-        cx.props.click_color.unwrap_or(NEUTRAL_500)
-    } else if **hovered {
-        cx.props.hover_color.unwrap_or(NEUTRAL_600)
-    } else {
-        cx.props.base_color.unwrap_or(NEUTRAL_800)
+            onclick: move |event: DioxusEvent<PointerButton>| {
+                if *event.data != PointerButton::Primary || editing {
+                    return;
+                }
+                let (target, text) = (target.clone(), edit_text.clone());
+                system_scheduler.schedule(move |world: &mut World| {
+                    *world.resource_mut::<StringEdit>() = StringEdit { target: Some(target), text };
+                });
+            },
+            text { text: "{name}: {shown_value}{cursor}" }
+        }
+    }
+}
+
+/// The bounds and display precision of an `f32` field, e.g. a camera's scale.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct NumericFieldRange {
+    min: f32,
+    max: f32,
+    precision: usize,
+}
+
+impl NumericFieldRange {
+    fn clamp(&self, value: f32) -> f32 {
+        value.clamp(self.min, self.max)
+    }
+
+    fn format(&self, value: f32) -> String {
+        format!("{value:.*}", self.precision)
+    }
+}
+
+/// Type data for a component, with the [`NumericFieldRange`] of its fields by reflect path.
+/// Fields without one are edited freely.
+#[derive(Clone)]
+struct NumericFieldRanges(Vec<(&'static str, NumericFieldRange)>);
+
+fn register_numeric_field_ranges(type_registry: Res<AppTypeRegistry>) {
+    let mut type_registry = type_registry.write();
+    let Some(registration) = type_registry.get_mut(TypeId::of::<OrthographicProjection>()) else {
+        return;
     };
+    registration.insert(NumericFieldRanges(vec![(
+        ".scale",
+        NumericFieldRange {
+            min: 0.1,
+            max: 10.0,
+            precision: 2,
+        },
+    )]));
+}
+
+/// The range registered for the field at `target`, if any.
+fn numeric_field_range(
+    target: &FieldTarget,
+    type_registry: &TypeRegistry,
+) -> Option<NumericFieldRange> {
+    let ranges = type_registry.get_type_data::<NumericFieldRanges>(target.type_id)?;
+    ranges
+        .0
+        .iter()
+        .find(|(path, _)| *path == target.path)
+        .map(|(_, range)| *range)
+}
+
+/// Writes an `f32` field, clamped to its [`NumericFieldRange`] if it has one.
+fn write_f32_field(target: &FieldTarget, value: f32, world: &mut World) {
+    let range = numeric_field_range(target, &world.resource::<AppTypeRegistry>().read());
+    let value = range.map_or(value, |range| range.clamp(value));
+    edit_field(target, world, |field: &mut f32| *field = value);
+}
+
+/// The string field being edited by typing, if any, and its text so far.
+#[derive(Resource, Default)]
+struct StringEdit {
+    target: Option<FieldTarget>,
+    text: String,
+}
+
+/// A `String` field that clicking edits: typing changes it, enter writes it to the field and
+/// escape discards it.
+#[component]
+fn StringField(cx: Scope, name: String, text: String, target: FieldTarget) -> Element {
+    let string_edit = use_resource_expect::<StringEdit>(cx);
+    let system_scheduler = use_system_scheduler(cx);
+    let editing = string_edit.target.as_ref() == Some(target);
+    let shown_text = if editing { &string_edit.text } else { text };
+    let cursor = if editing { "|" } else { "" };
 
     render! {
         node {
-            onclick: move |event| cx.props.onclick.call(event),
-            onclick_down: |event| if *event.data == PointerButton::Primary { clicked.set(true) },
-            onclick_up: |event| if *event.data == PointerButton::Primary { clicked.set(false) },
-            onmouse_enter: |_| hovered.set(true),
-            onmouse_exit: |_| { hovered.set(false); clicked.set(false) },
-            padding: "8",
-            background_color: background_color,
-            &cx.props.children
+            padding_left: "14",
+            onclick: move |event: DioxusEvent<PointerButton>| {
+                if *event.data != PointerButton::Primary || editing {
+                    return;
+                }
+                let (target, text) = (target.clone(), text.clone());
+                system_scheduler.schedule(move |world: &mut World| {
+                    *world.resource_mut::<StringEdit>() = StringEdit { target: Some(target), text };
+                });
+            },
+            text { text: "{name}: \"{shown_text}{cursor}\"" }
         }
     }
 }
 
-#[derive(Props)]
-struct ButtonProps<'a> {
-    onclick: EventHandler<'a, DioxusEvent<PointerButton>>,
-    base_color: Option<&'a str>,
-    click_color: Option<&'a str>,
-    hover_color: Option<&'a str>,
-    children: Element<'a>,
+/// Types into the [`StringEdit`], and writes it to its field on enter. Numbers that don't parse
+/// leave `f32` fields unchanged.
+fn type_into_string_edit(
+    mut characters: EventReader<ReceivedCharacter>,
+    keyboard: Res<Input<KeyCode>>,
+    mut string_edit: ResMut<StringEdit>,
+    mut commands: Commands,
+) {
+    if string_edit.target.is_none() {
+        characters.clear();
+        return;
+    }
+    for character in characters.read() {
+        if !character.char.is_control() {
+            string_edit.text.push(character.char);
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Back) {
+        string_edit.text.pop();
+    }
+    if keyboard.just_pressed(KeyCode::Escape) {
+        string_edit.target = None;
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::Return) {
+        let target = string_edit.target.take().unwrap();
+        let text = std::mem::take(&mut string_edit.text);
+        commands.add(move |world: &mut World| {
+            if read_field::<f32>(&target, world).is_none() {
+                write_string_field(&target, text, world);
+            } else if let Ok(value) = text.trim().parse() {
+                write_f32_field(&target, value, world);
+            }
+        });
+    }
+}
+
+/// Writes a `String` or `Cow<str>` field. `Name` caches a hash of its name, so it's replaced
+/// through `Name::new` instead.
+fn write_string_field(target: &FieldTarget, text: String, world: &mut World) {
+    if target.type_id == TypeId::of::<Name>() {
+        if let Some(mut entity) = world.get_entity_mut(target.entity) {
+            entity.insert(Name::new(text));
+        }
+        return;
+    }
+    edit_field(target, world, |value: &mut Cow<'static, str>| {
+        *value = Cow::Owned(text.clone());
+    });
+    edit_field(target, world, |value: &mut String| *value = text);
+}
+
+/// The text of a `String` or `Cow<str>`, e.g. `Name`'s field.
+fn reflect_str(value: &dyn Reflect) -> Option<&str> {
+    value
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| value.downcast_ref::<Cow<'static, str>>().map(AsRef::as_ref))
+}
+
+/// Reads a field of an entity's component through its reflection data.
+fn read_field<T: Reflect + Copy>(target: &FieldTarget, world: &World) -> Option<T> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_component = type_registry.get_type_data::<ReflectComponent>(target.type_id)?;
+    let component = reflect_component.reflect(world.get_entity(target.entity)?)?;
+    get_reflect_value(component, &target.path)?
+        .downcast_ref::<T>()
+        .copied()
+}
+
+/// Writes to a field of an entity's component through its reflection data.
+fn edit_field<T: Reflect>(target: &FieldTarget, world: &mut World, edit: impl FnOnce(&mut T)) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let Some(reflect_component) = type_registry.get_type_data::<ReflectComponent>(target.type_id)
+    else {
+        return;
+    };
+    let Some(mut entity) = world.get_entity_mut(target.entity) else {
+        return;
+    };
+    let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+        return;
+    };
+    if let Some(value) =
+        get_reflect_value_mut(&mut *component, &target.path).and_then(|field| field.downcast_mut())
+    {
+        edit(value);
+    }
+}
+
+/// Returns the field of `value` at a dotted path like `translation.x`, through nested structs,
+/// tuples, lists and enums, or `None` if there's no such field.
+fn get_reflect_value<'a>(value: &'a dyn Reflect, path: &str) -> Option<&'a dyn Reflect> {
+    value.reflect_path(path).ok()
+}
+
+/// Like [`get_reflect_value`], to write to the field.
+fn get_reflect_value_mut<'a>(
+    value: &'a mut dyn Reflect,
+    path: &str,
+) -> Option<&'a mut dyn Reflect> {
+    value.reflect_path_mut(path).ok()
+}
+
+/// Shows `Entity`s by id and asset `Handle`s by path, rather than as their opaque internals.
+// TODO: Make entities clickable to select them in the scene tree
+fn describe_reference(
+    value: &dyn Reflect,
+    type_registry: &TypeRegistry,
+    asset_server: &AssetServer,
+) -> Option<String> {
+    if let Some(entity) = value.downcast_ref::<Entity>() {
+        return Some(format!("Entity ({entity:?})"));
+    }
+
+    // Only asset types registered with `App::register_asset_reflect` have `ReflectHandle`
+    let handle = type_registry
+        .get_type_data::<ReflectHandle>(value.as_any().type_id())?
+        .downcast_handle_untyped(value.as_any())?;
+    let asset = value.reflect_short_type_path();
+    Some(match asset_server.get_path(handle.id()) {
+        Some(path) => format!("{asset} (\"{path}\")"),
+        None => format!("{asset} ({:?})", handle.id()),
+    })
+}
+
+fn reflect_summary(value: &dyn Reflect) -> String {
+    match value.reflect_ref() {
+        ReflectRef::List(list) if list.is_empty() => "[]".to_owned(),
+        ReflectRef::List(list) => format!("[{} items]", list.len()),
+        ReflectRef::Array(array) => format!("[{} items]", array.len()),
+        ReflectRef::Map(map) => format!("{{{} entries}}", map.len()),
+        ReflectRef::Enum(value) if value.field_len() == 0 => value.variant_name().to_owned(),
+        // Show e.g. `Some(5)` inline rather than as an expandable field
+        ReflectRef::Enum(value) => match value.field_at(0) {
+            Some(field)
+                if value.field_len() == 1
+                    && matches!(field.reflect_ref(), ReflectRef::Value(_)) =>
+            {
+                format!("{}({field:?})", value.variant_name())
+            }
+            _ => format!("{}(..)", value.variant_name()),
+        },
+        ReflectRef::Value(value) => format!("{value:?}"),
+        ReflectRef::Struct(_) | ReflectRef::TupleStruct(_) | ReflectRef::Tuple(_) => {
+            value.reflect_short_type_path().to_owned()
+        }
+    }
+}
+
+/// Returns each field's name, its reflect path segment if it can be written to by path, and value.
+fn reflect_fields(value: &dyn Reflect) -> Vec<(String, Option<String>, &dyn Reflect)> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .map(|index| {
+                let name = value.name_at(index).unwrap().to_owned();
+                (
+                    name.clone(),
+                    Some(format!(".{name}")),
+                    value.field_at(index).unwrap(),
+                )
+            })
+            .collect(),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, field)| (index.to_string(), Some(format!(".{index}")), field))
+            .collect(),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, field)| (index.to_string(), Some(format!(".{index}")), field))
+            .collect(),
+        ReflectRef::List(value) => value
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (index.to_string(), Some(format!("[{index}]")), item))
+            .collect(),
+        ReflectRef::Array(value) => value
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (index.to_string(), Some(format!("[{index}]")), item))
+            .collect(),
+        // Map keys can't be part of a reflect path
+        ReflectRef::Map(value) => value
+            .iter()
+            .map(|(key, value)| (format!("{key:?}"), None, value))
+            .collect(),
+        ReflectRef::Enum(value)
+            if value.field_len() == 1
+                && matches!(
+                    value.field_at(0).unwrap().reflect_ref(),
+                    ReflectRef::Value(_)
+                ) =>
+        {
+            Vec::new()
+        }
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, field)| {
+                let name = field.name().map(str::to_owned);
+                let name = name.unwrap_or_else(|| index.to_string());
+                (name.clone(), Some(format!(".{name}")), field.value())
+            })
+            .collect(),
+        ReflectRef::Value(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn world_with_projection() -> (World, FieldTarget) {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<OrthographicProjection>();
+        let entity = world.spawn(OrthographicProjection::default()).id();
+        let target = FieldTarget {
+            entity,
+            type_id: TypeId::of::<OrthographicProjection>(),
+            path: ".scale".to_owned(),
+        };
+        (world, target)
+    }
+
+    #[test]
+    fn registered_range_clamps_the_value() {
+        let (mut world, target) = world_with_projection();
+        world.run_system_once(register_numeric_field_ranges);
+
+        write_f32_field(&target, 100.0, &mut world);
+        assert_eq!(read_field::<f32>(&target, &world), Some(10.0));
+        write_f32_field(&target, -1.0, &mut world);
+        assert_eq!(read_field::<f32>(&target, &world), Some(0.1));
+    }
+
+    #[test]
+    fn fields_without_a_range_are_written_freely() {
+        let (mut world, target) = world_with_projection();
+
+        write_f32_field(&target, 100.0, &mut world);
+        assert_eq!(read_field::<f32>(&target, &world), Some(100.0));
+    }
 }
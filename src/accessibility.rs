@@ -0,0 +1,383 @@
+use crate::{events::HasClickEventListener, DioxusUiRoot};
+use bevy::{
+    a11y::{
+        accesskit::{Action, DefaultActionVerb, Live, NodeBuilder, NodeId, Role},
+        AccessibilityNode, ActionRequest, Focus,
+    },
+    ecs::{
+        component::Component,
+        entity::Entity,
+        event::EventReader,
+        query::{Added, Changed, Has, Or, With},
+        system::{Commands, Query, Res, ResMut},
+    },
+    hierarchy::{Children, Parent},
+    input::{keyboard::KeyCode, Input},
+    render::view::InheritedVisibility,
+    text::Text,
+    utils::HashSet,
+};
+use bevy_mod_picking::events::{Click, Pointer};
+
+/// The `role`, `aria_label`, `aria_live`, `aria_hidden` and `tab_index` attributes of an element.
+///
+/// Every element spawned by bevy_dioxus has this component, even if neither attribute is set, so
+/// that default roles are only given to dioxus elements.
+#[derive(Component, Default, Clone)]
+pub(crate) struct AccessibilityAttributes {
+    pub role: Option<String>,
+    pub aria_label: Option<String>,
+    pub aria_live: Option<Live>,
+    /// Leaves the element and its descendants out of the accessibility tree, e.g. for decorative
+    /// images and spacers, while still showing them.
+    pub aria_hidden: bool,
+    pub tab_index: Option<i32>,
+}
+
+impl AccessibilityAttributes {
+    pub fn set_attribute(&mut self, name: &str, value: Option<&str>) {
+        match name {
+            "role" => self.role = value.map(str::to_owned),
+            "aria_label" => self.aria_label = value.map(str::to_owned),
+            "aria_live" => self.aria_live = value.map(parse_live),
+            "aria_hidden" => self.aria_hidden = value.is_some_and(parse_aria_hidden),
+            "tab_index" => {
+                self.tab_index = value.map(|value| {
+                    value.parse().unwrap_or_else(|_| {
+                        panic!("Encountered invalid bevy_dioxus tab_index `{value}`.")
+                    })
+                });
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub(crate) fn is_accessibility_attribute(name: &str) -> bool {
+    matches!(
+        name,
+        "role" | "aria_label" | "aria_live" | "aria_hidden" | "tab_index"
+    )
+}
+
+/// Marks elements that were left out of the accessibility tree by `aria_hidden` the last time
+/// their node was rebuilt, to rebuild their descendants once `aria_hidden` changes.
+#[derive(Component)]
+pub(crate) struct AriaHidden;
+
+/// Rebuilds the AccessKit node of every element whose attributes, text or children changed, and
+/// of the parents of changed text, since their names are built from it. Elements with an
+/// `aria_hidden` ancestor, or `aria_hidden` themselves, get no node.
+///
+/// Screen readers announce name changes of `aria_live` elements, so status messages are read out
+/// without being focused. AccessKit only consumes these nodes while an assistive technology is
+/// active.
+// TODO: Richer semantics (checked/expanded states, descriptions)
+pub fn update_accessibility_nodes(
+    mut commands: Commands,
+    changed_elements: Query<
+        Entity,
+        (
+            With<AccessibilityAttributes>,
+            Or<(
+                Changed<AccessibilityAttributes>,
+                Changed<Text>,
+                Changed<Children>,
+                Added<HasClickEventListener>,
+            )>,
+        ),
+    >,
+    changed_texts: Query<&Parent, Changed<Text>>,
+    elements: Query<(
+        &AccessibilityAttributes,
+        Option<&Text>,
+        Has<HasClickEventListener>,
+        Option<&Children>,
+    )>,
+    texts: Query<&Text>,
+    hidden_elements: Query<(&AccessibilityAttributes, Has<AriaHidden>)>,
+    parents: Query<&Parent>,
+) {
+    let mut changed = changed_elements
+        .iter()
+        .chain(changed_texts.iter().map(Parent::get))
+        .collect::<HashSet<_>>();
+    // Hiding or showing an element adds or removes the nodes of its whole subtree
+    let toggled = changed_elements
+        .iter()
+        .filter(|entity| {
+            hidden_elements
+                .get(*entity)
+                .is_ok_and(|(attributes, was_hidden)| attributes.aria_hidden != was_hidden)
+        })
+        .collect::<Vec<_>>();
+    for entity in toggled {
+        match hidden_elements.get(entity) {
+            Ok((attributes, _)) if attributes.aria_hidden => {
+                commands.entity(entity).insert(AriaHidden);
+            }
+            _ => {
+                commands.entity(entity).remove::<AriaHidden>();
+            }
+        }
+        let mut stack = vec![entity];
+        while let Some(entity) = stack.pop() {
+            changed.insert(entity);
+            if let Ok((_, _, _, Some(children))) = elements.get(entity) {
+                stack.extend(children.iter());
+            }
+        }
+    }
+
+    for entity in changed {
+        let Ok((attributes, text, has_click_listener, children)) = elements.get(entity) else {
+            continue;
+        };
+        if is_aria_hidden(entity, &hidden_elements, &parents) {
+            commands.entity(entity).remove::<AccessibilityNode>();
+            continue;
+        }
+        let role = match attributes.role.as_deref() {
+            Some("none") => None,
+            Some(role) => Some(parse_role(role)),
+            None if has_click_listener => Some(Role::Button),
+            None if text.is_some() => Some(Role::StaticText),
+            None if attributes.tab_index.is_some() || attributes.aria_live.is_some() => {
+                Some(Role::Group)
+            }
+            None => None,
+        };
+        let Some(role) = role else {
+            commands.entity(entity).remove::<AccessibilityNode>();
+            continue;
+        };
+
+        let name = attributes.aria_label.clone().or_else(|| match text {
+            Some(text) => Some(text_value(text)),
+            None => children.map(|children| {
+                children
+                    .iter()
+                    .filter_map(|child| texts.get(*child).ok())
+                    .map(text_value)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }),
+        });
+
+        let mut node = NodeBuilder::new(role);
+        if let Some(name) = name.filter(|name| !name.is_empty()) {
+            node.set_name(name);
+        }
+        if is_focusable(role) || attributes.tab_index.is_some() {
+            node.add_action(Action::Focus);
+            node.set_default_action_verb(DefaultActionVerb::Click);
+        }
+        if let Some(live) = attributes.aria_live {
+            node.set_live(live);
+        }
+        commands.entity(entity).insert(AccessibilityNode(node));
+    }
+}
+
+/// Moves accessibility focus to the nearest focusable ancestor of a clicked element, or to the
+/// element an assistive technology requested focus for.
+pub fn update_accessibility_focus(
+    mut clicks: EventReader<Pointer<Click>>,
+    mut action_requests: EventReader<ActionRequest>,
+    elements: Query<(
+        Option<&AccessibilityAttributes>,
+        Has<HasClickEventListener>,
+        Has<AccessibilityNode>,
+        Option<&Parent>,
+    )>,
+    mut focus: ResMut<Focus>,
+) {
+    let is_focusable_element = |entity| {
+        matches!(
+            elements.get(entity),
+            Ok((Some(attributes), has_click_listener, true, _))
+                if element_is_focusable(attributes, has_click_listener)
+        )
+    };
+
+    for click in clicks.read() {
+        let mut target = Some(click.target);
+        while let Some(entity) = target {
+            if is_focusable_element(entity) {
+                focus.0 = Some(entity);
+                break;
+            }
+            target = elements
+                .get(entity)
+                .ok()
+                .and_then(|(_, _, _, parent)| parent.map(Parent::get));
+        }
+    }
+
+    for request in action_requests.read() {
+        if request.action == Action::Focus {
+            let NodeId(id) = request.target;
+            let entity = Entity::from_bits(id.into());
+            if is_focusable_element(entity) {
+                focus.0 = Some(entity);
+            }
+        }
+    }
+}
+
+/// Moves focus to the next element in tab order on Tab, or the previous one on Shift+Tab.
+///
+/// Elements with a positive `tab_index` come first in ascending order, then the rest in document
+/// order. Elements with a negative `tab_index` can only be focused by clicking.
+pub fn navigate_focus_with_tab(
+    input: Option<Res<Input<KeyCode>>>,
+    roots: Query<Entity, With<DioxusUiRoot>>,
+    elements: Query<(
+        Option<&AccessibilityAttributes>,
+        Has<HasClickEventListener>,
+        Has<AccessibilityNode>,
+        Option<&InheritedVisibility>,
+        Option<&Children>,
+    )>,
+    mut focus: ResMut<Focus>,
+) {
+    let Some(input) = input else {
+        return;
+    };
+    if !input.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    let backwards = input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    // Depth-first, so the order matches the order elements are written in
+    let mut tab_order = Vec::new();
+    let mut roots = roots.iter().collect::<Vec<_>>();
+    roots.sort();
+    let mut stack = roots.into_iter().rev().collect::<Vec<_>>();
+    while let Some(entity) = stack.pop() {
+        let Ok((attributes, has_click_listener, has_accessibility_node, visibility, children)) =
+            elements.get(entity)
+        else {
+            continue;
+        };
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            continue;
+        }
+        if let Some(attributes) = attributes {
+            let tab_index = attributes.tab_index.unwrap_or(0);
+            if has_accessibility_node
+                && tab_index >= 0
+                && element_is_focusable(attributes, has_click_listener)
+            {
+                tab_order.push((tab_index, entity));
+            }
+        }
+        if let Some(children) = children {
+            stack.extend(children.iter().rev());
+        }
+    }
+    // Stable, so elements with the same index keep their document order
+    tab_order.sort_by_key(|(tab_index, _)| if *tab_index > 0 { *tab_index } else { i32::MAX });
+    if tab_order.is_empty() {
+        return;
+    }
+
+    let current = focus
+        .0
+        .and_then(|focused| tab_order.iter().position(|(_, entity)| *entity == focused));
+    let next = match (current, backwards) {
+        (Some(index), false) => (index + 1) % tab_order.len(),
+        (Some(index), true) => (index + tab_order.len() - 1) % tab_order.len(),
+        (None, false) => 0,
+        (None, true) => tab_order.len() - 1,
+    };
+    focus.0 = Some(tab_order[next].1);
+}
+
+fn element_is_focusable(attributes: &AccessibilityAttributes, has_click_listener: bool) -> bool {
+    if attributes.tab_index.is_some() {
+        return true;
+    }
+    match attributes.role.as_deref() {
+        Some("none") => false,
+        Some(role) => is_focusable(parse_role(role)),
+        None => has_click_listener,
+    }
+}
+
+fn is_focusable(role: Role) -> bool {
+    matches!(
+        role,
+        Role::Button
+            | Role::CheckBox
+            | Role::Link
+            | Role::MenuItem
+            | Role::Slider
+            | Role::Tab
+            | Role::TextInput
+    )
+}
+
+fn parse_role(role: &str) -> Role {
+    match role {
+        "button" => Role::Button,
+        "checkbox" => Role::CheckBox,
+        "group" => Role::Group,
+        "heading" => Role::Heading,
+        "image" => Role::Image,
+        "label" => Role::StaticText,
+        "link" => Role::Link,
+        "list" => Role::List,
+        "list_item" => Role::ListItem,
+        "menu" => Role::Menu,
+        "menu_item" => Role::MenuItem,
+        "slider" => Role::Slider,
+        "tab" => Role::Tab,
+        "text_input" => Role::TextInput,
+        _ => panic!("Encountered unsupported bevy_dioxus role `{role}`."),
+    }
+}
+
+fn is_aria_hidden(
+    mut entity: Entity,
+    elements: &Query<(&AccessibilityAttributes, Has<AriaHidden>)>,
+    parents: &Query<&Parent>,
+) -> bool {
+    loop {
+        if elements
+            .get(entity)
+            .is_ok_and(|(attributes, _)| attributes.aria_hidden)
+        {
+            return true;
+        }
+        let Ok(parent) = parents.get(entity) else {
+            return false;
+        };
+        entity = parent.get();
+    }
+}
+
+fn parse_aria_hidden(aria_hidden: &str) -> bool {
+    match aria_hidden {
+        "true" => true,
+        "false" => false,
+        _ => panic!("Encountered unsupported bevy_dioxus aria_hidden `{aria_hidden}`."),
+    }
+}
+
+fn parse_live(live: &str) -> Live {
+    match live {
+        "off" => Live::Off,
+        "polite" => Live::Polite,
+        "assertive" => Live::Assertive,
+        _ => panic!("Encountered unsupported bevy_dioxus aria_live `{live}`."),
+    }
+}
+
+fn text_value(text: &Text) -> String {
+    text.sections
+        .iter()
+        .map(|section| section.value.as_str())
+        .collect::<Vec<_>>()
+        .join("")
+}
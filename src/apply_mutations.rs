@@ -1,10 +1,31 @@
 use crate::{
-    events::{insert_event_listener, remove_event_listener},
-    parse_attributes::set_attribute,
+    accessibility::{is_accessibility_attribute, AccessibilityAttributes},
+    cursor::{is_cursor_attribute, set_cursor_attribute, UiCursor},
+    events::{insert_event_listener, remove_event_listener, DragData},
+    interaction::{
+        is_interaction_attribute, set_interaction_attribute, InteractionBackgroundColors,
+    },
+    object_fit::{set_object_fit_attribute, ObjectFit},
+    parse_attributes::{reset_attribute, set_attribute, TextTransform},
+    scroll::{
+        is_overflow_attribute, is_scrollbar_attribute, parse_sticky, set_scroll_attribute,
+        set_sticky_attribute, Scroll, Sticky,
+    },
+    stylesheet::Classes,
+    text_inheritance::{is_inheritable_text_attribute, InheritableTextStyle},
+    text_shadow::{set_text_shadow_attribute, TextShadow},
+    theme::{is_theme_value, DioxusTheme, ThemedAttributes},
+    use_node_ref::NodeRefs,
 };
 use bevy::{
     asset::AssetServer,
-    ecs::{entity::Entity, system::Command, world::World},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{Changed, ReadOnlyWorldQuery},
+        system::Command,
+        world::World,
+    },
     hierarchy::{BuildWorldChildren, Children, DespawnRecursive, Parent},
     prelude::default,
     render::{color::Color, view::Visibility},
@@ -29,12 +50,13 @@ pub fn apply_mutations(
     templates: &mut HashMap<String, BevyTemplate>,
     root_entity: Entity,
     world: &mut World,
+    theme: &DioxusTheme,
     asset_server: &AssetServer,
 ) {
     for new_template in mutations.templates {
         templates.insert(
             new_template.name.to_owned(),
-            BevyTemplate::from_dioxus(&new_template, asset_server),
+            BevyTemplate::from_dioxus(&new_template, theme, asset_server),
         );
     }
 
@@ -67,6 +89,7 @@ pub fn apply_mutations(
             Mutation::CreateTextNode { value, id } => {
                 let entity = BevyTemplateNode::from_dioxus(
                     &TemplateNode::Text { text: value },
+                    theme,
                     asset_server,
                 )
                 .spawn(world);
@@ -165,64 +188,118 @@ pub fn apply_mutations(
                 id,
                 ns: _,
             } => {
+                // `None` resets the attribute, e.g. `width: if wide { Some("100%") } else { None }`
                 let value = match value {
-                    BorrowedAttributeValue::Text(value) => value,
-                    BorrowedAttributeValue::None => todo!("Remove the attribute"),
+                    BorrowedAttributeValue::Text(value) => Some(value),
+                    BorrowedAttributeValue::None => None,
                     value => {
                         panic!("Encountered unsupported bevy_dioxus attribute `{name}: {value:?}`.")
                     }
                 };
 
-                let (
-                    mut style,
-                    mut border_color,
-                    mut outline,
-                    mut background_color,
-                    mut transform,
-                    mut visibility,
-                    mut z_index,
-                    mut text,
-                    mut image,
-                ) = world
-                    .query::<(
-                        &mut Style,
-                        &mut BorderColor,
-                        &mut Outline,
-                        &mut BackgroundColor,
-                        &mut Transform,
-                        &mut Visibility,
-                        &mut ZIndex,
-                        Option<&mut Text>,
-                        Option<&mut UiImage>,
-                    )>()
-                    .get_mut(world, element_id_to_bevy_ui_entity[&id])
-                    .unwrap();
+                let entity = element_id_to_bevy_ui_entity[&id];
+                if name == "node_ref" {
+                    if let Some(value) = value {
+                        let node_ref_id = value.parse::<u64>().unwrap_or_else(|_| {
+                            panic!("Encountered invalid bevy_dioxus node_ref `{value}`.")
+                        });
+                        world
+                            .resource_mut::<NodeRefs>()
+                            .entities
+                            .insert(node_ref_id, entity);
+                    }
+                    continue;
+                }
+
+                // The entity may have been despawned by a deferred system outside of dioxus
+                let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                    continue;
+                };
+                if name == "class" {
+                    let value = value.unwrap_or_default();
+                    match entity_mut.get_mut::<Classes>() {
+                        Some(mut classes) => classes.classes = value.to_owned(),
+                        None => {
+                            entity_mut.insert(Classes {
+                                classes: value.to_owned(),
+                                ..default()
+                            });
+                        }
+                    }
+                    continue;
+                }
+                if let Some(mut classes) = entity_mut.get_mut::<Classes>() {
+                    // Without classes there is nothing to re-apply the inline attributes over
+                    let classes = if classes.classes.is_empty() {
+                        classes.bypass_change_detection()
+                    } else {
+                        &mut *classes
+                    };
+                    match value {
+                        Some(value) => {
+                            classes
+                                .inline_attributes
+                                .insert(name.to_owned(), value.to_owned());
+                        }
+                        None => {
+                            classes.inline_attributes.remove(name);
+                        }
+                    }
+                }
 
-                set_attribute(
+                match value {
+                    Some(value) if is_theme_value(value) => {
+                        if !entity_mut.contains::<ThemedAttributes>() {
+                            entity_mut.insert(ThemedAttributes::default());
+                        }
+                        let mut themed_attributes =
+                            entity_mut.get_mut::<ThemedAttributes>().unwrap();
+                        themed_attributes
+                            .0
+                            .insert(name.to_owned(), value.to_owned());
+                    }
+                    _ => {
+                        if let Some(mut themed_attributes) =
+                            entity_mut.get_mut::<ThemedAttributes>()
+                        {
+                            themed_attributes.0.remove(name);
+                        }
+                    }
+                }
+
+                set_entity_attribute(
+                    entity,
                     name,
-                    value,
-                    &mut style,
-                    &mut border_color,
-                    &mut outline,
-                    &mut background_color,
-                    &mut transform,
-                    &mut visibility,
-                    &mut z_index,
-                    text.as_deref_mut(),
-                    image.as_deref_mut(),
+                    value.map(|value| theme.resolve(value)),
+                    world,
                     asset_server,
                 );
             }
             Mutation::SetText { value, id } => {
-                world
-                    .entity_mut(element_id_to_bevy_ui_entity[&id])
-                    .insert(Text::from_section(value, TextStyle::default()));
+                if let Some(mut entity) = world.get_entity_mut(element_id_to_bevy_ui_entity[&id]) {
+                    // Update the existing section in place to keep its style and change detection
+                    // limited to the text itself
+                    match entity.get_mut::<Text>() {
+                        Some(mut text) => {
+                            if text.sections[0].value != value {
+                                text.sections[0].value = value.to_owned();
+                            }
+                        }
+                        None => {
+                            entity.insert(Text::from_section(value, TextStyle::default()));
+                        }
+                    }
+                }
             }
             Mutation::NewEventListener { name, id } => {
-                insert_event_listener(name, world.entity_mut(element_id_to_bevy_ui_entity[&id]));
+                if let Some(entity) = world.get_entity_mut(element_id_to_bevy_ui_entity[&id]) {
+                    insert_event_listener(name, entity);
+                }
             }
             Mutation::RemoveEventListener { name, id } => {
-                remove_event_listener(name, world.entity_mut(element_id_to_bevy_ui_entity[&id]));
+                if let Some(entity) = world.get_entity_mut(element_id_to_bevy_ui_entity[&id]) {
+                    remove_event_listener(name, entity);
+                }
             }
             Mutation::Remove { id } => {
                 let entity = element_id_to_bevy_ui_entity[&id];
@@ -235,8 +312,227 @@ pub fn apply_mutations(
             Mutation::PushRoot { id } => stack.push(element_id_to_bevy_ui_entity[&id]),
         }
     }
+
+    apply_classes::<Changed<Classes>>(world, theme, asset_server);
+}
+
+pub fn apply_classes<F: ReadOnlyWorldQuery>(
+    world: &mut World,
+    theme: &DioxusTheme,
+    asset_server: &AssetServer,
+) {
+    let classed_entities = world
+        .query_filtered::<(Entity, &Classes), F>()
+        .iter(world)
+        .filter(|(_, classes)| !classes.classes.is_empty())
+        .map(|(entity, classes)| (entity, classes.attributes(world)))
+        .collect::<Vec<_>>();
+
+    for (entity, attributes) in classed_entities {
+        for (name, value) in attributes {
+            set_entity_attribute(
+                entity,
+                &name,
+                Some(theme.resolve(&value)),
+                world,
+                asset_server,
+            );
+        }
+    }
+}
+
+pub fn apply_theme(world: &mut World, theme: &DioxusTheme, asset_server: &AssetServer) {
+    let themed_entities = world
+        .query::<(Entity, &ThemedAttributes)>()
+        .iter(world)
+        .map(|(entity, themed_attributes)| (entity, themed_attributes.clone()))
+        .collect::<Vec<_>>();
+
+    for (entity, themed_attributes) in themed_entities {
+        for (name, value) in &themed_attributes.0 {
+            set_entity_attribute(
+                entity,
+                name,
+                Some(theme.resolve(value)),
+                world,
+                asset_server,
+            );
+        }
+    }
 }
 
+fn set_entity_attribute(
+    entity: Entity,
+    name: &str,
+    value: Option<&str>,
+    world: &mut World,
+    asset_server: &AssetServer,
+) {
+    if is_interaction_attribute(name) {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_interaction_attribute(name, value, &mut entity_mut);
+        }
+        return;
+    }
+    if name == "text_shadow" {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_text_shadow_attribute(value, &mut entity_mut);
+        }
+        return;
+    }
+    if name == "object_fit" {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_object_fit_attribute(value, &mut entity_mut);
+        }
+        return;
+    }
+    if is_cursor_attribute(name) {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_cursor_attribute(name, value, &mut entity_mut, asset_server);
+        }
+        return;
+    }
+    if name == "drag_data" {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            match value {
+                Some(value) => entity_mut.insert(DragData(value.to_owned())),
+                None => entity_mut.remove::<DragData>(),
+            };
+        }
+        return;
+    }
+    if name == "sticky" {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_sticky_attribute(value, &mut entity_mut);
+        }
+        return;
+    }
+    if is_inheritable_text_attribute(name) {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            let mut inheritable_text_style = entity_mut
+                .get::<InheritableTextStyle>()
+                .cloned()
+                .unwrap_or_default();
+            inheritable_text_style.set_attribute(name, value);
+            entity_mut.insert(inheritable_text_style);
+            // Other elements only pass them down to their text
+            if !entity_mut.contains::<Text>() {
+                return;
+            }
+        }
+    }
+    if is_scrollbar_attribute(name) {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_scroll_attribute(name, value, &mut entity_mut);
+        }
+        return;
+    }
+    // Also sets the `Style`'s overflow below
+    if is_overflow_attribute(name) {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            set_scroll_attribute(name, value, &mut entity_mut);
+        }
+    }
+
+    let Ok((
+        mut style,
+        mut border_color,
+        mut outline,
+        mut background_color,
+        mut transform,
+        mut visibility,
+        mut z_index,
+        mut text,
+        mut text_transform,
+        mut image,
+        accessibility_attributes,
+        interaction_background_colors,
+        text_shadow,
+        object_fit,
+    )) = world
+        .query::<(
+            &mut Style,
+            &mut BorderColor,
+            &mut Outline,
+            &mut BackgroundColor,
+            &mut Transform,
+            &mut Visibility,
+            &mut ZIndex,
+            Option<&mut Text>,
+            Option<&mut TextTransform>,
+            Option<&mut UiImage>,
+            Option<&mut AccessibilityAttributes>,
+            Option<&mut InteractionBackgroundColors>,
+            Option<&mut TextShadow>,
+            Option<&mut ObjectFit>,
+        )>()
+        .get_mut(world, entity)
+    else {
+        return;
+    };
+
+    // Skip unchanged text so idle labels don't trigger change detection and text re-layout
+    if let (Some(value), Some(text_transform)) = (value, &text_transform) {
+        if name == "text" && text_transform.text() == value {
+            return;
+        }
+    }
+
+    if is_accessibility_attribute(name) {
+        if let Some(mut accessibility_attributes) = accessibility_attributes {
+            accessibility_attributes.set_attribute(name, value);
+        }
+        return;
+    }
+
+    match value {
+        Some(value) => set_attribute(
+            name,
+            value,
+            &mut style,
+            &mut border_color,
+            &mut outline,
+            &mut background_color,
+            &mut transform,
+            &mut visibility,
+            &mut z_index,
+            text.as_deref_mut(),
+            text_transform.as_deref_mut(),
+            image.as_deref_mut(),
+            asset_server,
+        ),
+        None => reset_attribute(
+            name,
+            &mut style,
+            &mut border_color,
+            &mut outline,
+            &mut background_color,
+            &mut transform,
+            &mut visibility,
+            &mut z_index,
+            text.as_deref_mut(),
+            text_transform.as_deref_mut(),
+            image.as_deref_mut(),
+        ),
+    }
+
+    if name == "background_color" {
+        if let Some(mut interaction_background_colors) = interaction_background_colors {
+            interaction_background_colors.base = background_color.0;
+        }
+    }
+    if let ("text_color", Some(text), Some(mut text_shadow)) = (name, text, text_shadow) {
+        text_shadow.text_color = text.sections[0].style.color;
+    }
+    if let ("scale" | "scale_x" | "scale_y", Some(mut object_fit)) = (name, object_fit) {
+        object_fit.scale = transform.scale.truncate();
+    }
+}
+
+/// Marks the elements bevy_dioxus spawns from templates, so its systems leave other UI alone.
+#[derive(Component)]
+pub(crate) struct DioxusNode;
+
 pub struct BevyTemplate {
     roots: Box<[BevyTemplateNode]>,
 }
@@ -248,6 +544,7 @@ enum BevyTemplateNode {
     },
     TextNode {
         text: Text,
+        text_transform: TextTransform,
         style: StyleComponents,
         children: Box<[Self]>,
     },
@@ -260,19 +557,25 @@ enum BevyTemplateNode {
 }
 
 impl BevyTemplate {
-    fn from_dioxus(template: &Template, asset_server: &AssetServer) -> Self {
+    fn from_dioxus(template: &Template, theme: &DioxusTheme, asset_server: &AssetServer) -> Self {
         Self {
             roots: template
                 .roots
                 .iter()
-                .map(|node| BevyTemplateNode::from_dioxus(node, asset_server))
+                .map(|node| BevyTemplateNode::from_dioxus(node, theme, asset_server))
                 .collect(),
         }
     }
+
+    pub fn apply_theme(&mut self, theme: &DioxusTheme, asset_server: &AssetServer) {
+        for root in self.roots.iter_mut() {
+            root.apply_theme(theme, asset_server);
+        }
+    }
 }
 
 impl BevyTemplateNode {
-    fn from_dioxus(node: &TemplateNode, asset_server: &AssetServer) -> Self {
+    fn from_dioxus(node: &TemplateNode, theme: &DioxusTheme, asset_server: &AssetServer) -> Self {
         match node {
             TemplateNode::Element {
                 tag: "node",
@@ -280,12 +583,13 @@ impl BevyTemplateNode {
                 attrs,
                 children,
             } => {
-                let (style, _, _) = parse_template_attributes(attrs, Color::NONE, asset_server);
+                let (style, _, _, _) =
+                    parse_template_attributes(attrs, Color::NONE, theme, asset_server);
                 Self::Node {
                     style,
                     children: children
                         .iter()
-                        .map(|node| Self::from_dioxus(node, asset_server))
+                        .map(|node| Self::from_dioxus(node, theme, asset_server))
                         .collect(),
                 }
             }
@@ -295,13 +599,15 @@ impl BevyTemplateNode {
                 attrs,
                 children,
             } => {
-                let (style, text, _) = parse_template_attributes(attrs, Color::NONE, asset_server);
+                let (style, text, text_transform, _) =
+                    parse_template_attributes(attrs, Color::NONE, theme, asset_server);
                 Self::TextNode {
                     text,
+                    text_transform,
                     style,
                     children: children
                         .iter()
-                        .map(|node| Self::from_dioxus(node, asset_server))
+                        .map(|node| Self::from_dioxus(node, theme, asset_server))
                         .collect(),
                 }
             }
@@ -311,14 +617,14 @@ impl BevyTemplateNode {
                 attrs,
                 children,
             } => {
-                let (style, _, image) =
-                    parse_template_attributes(attrs, Color::WHITE, asset_server);
+                let (style, _, _, image) =
+                    parse_template_attributes(attrs, Color::WHITE, theme, asset_server);
                 Self::ImageNode {
                     image,
                     style,
                     children: children
                         .iter()
-                        .map(|node| Self::from_dioxus(node, asset_server))
+                        .map(|node| Self::from_dioxus(node, theme, asset_server))
                         .collect(),
                 }
             }
@@ -349,7 +655,107 @@ impl BevyTemplateNode {
         }
     }
 
+    fn apply_theme(&mut self, theme: &DioxusTheme, asset_server: &AssetServer) {
+        let (style, mut text, mut text_transform, mut image, children) = match self {
+            Self::Node { style, children } => (style, None, None, None, children),
+            Self::TextNode {
+                text,
+                text_transform,
+                style,
+                children,
+            } => (style, Some(text), Some(text_transform), None, children),
+            Self::ImageNode {
+                image,
+                style,
+                children,
+            } => (style, None, None, Some(image), children),
+            Self::IntrinsicTextNode(_) => return,
+        };
+
+        for (name, value) in style.themed_attributes.0.clone() {
+            style.set_attribute(
+                &name,
+                theme.resolve(&value),
+                text.as_deref_mut(),
+                text_transform.as_deref_mut(),
+                image.as_deref_mut(),
+                asset_server,
+            );
+        }
+        for child in children.iter_mut() {
+            child.apply_theme(theme, asset_server);
+        }
+    }
+
+    fn style(&self) -> Option<&StyleComponents> {
+        match self {
+            Self::Node { style, .. }
+            | Self::TextNode { style, .. }
+            | Self::ImageNode { style, .. } => Some(style),
+            Self::IntrinsicTextNode(_) => None,
+        }
+    }
+
     fn spawn(&self, world: &mut World) -> Entity {
+        let entity = self.spawn_entity(world);
+        world.entity_mut(entity).insert((
+            DioxusNode,
+            self.style()
+                .map(|style| style.accessibility_attributes.clone())
+                .unwrap_or_default(),
+        ));
+        if let Some(style) = self.style() {
+            if !style.themed_attributes.0.is_empty() {
+                world
+                    .entity_mut(entity)
+                    .insert(style.themed_attributes.clone());
+            }
+            if let Some(classes) = &style.classes {
+                world.entity_mut(entity).insert(classes.clone());
+            }
+            if let Some(interaction_background_colors) = &style.interaction_background_colors {
+                world.entity_mut(entity).insert((
+                    InteractionBackgroundColors {
+                        base: style.background_color.0,
+                        ..interaction_background_colors.clone()
+                    },
+                    Interaction::default(),
+                ));
+            }
+            if let (Some(text_shadow), Self::TextNode { text, .. }) = (&style.text_shadow, self) {
+                world.entity_mut(entity).insert(TextShadow {
+                    text_color: text.sections[0].style.color,
+                    ..text_shadow.clone()
+                });
+            }
+            if let Some(drag_data) = &style.drag_data {
+                world.entity_mut(entity).insert(drag_data.clone());
+            }
+            if let Some(sticky) = &style.sticky {
+                world.entity_mut(entity).insert(sticky.clone());
+            }
+            if let Some(object_fit) = &style.object_fit {
+                world.entity_mut(entity).insert(ObjectFit {
+                    scale: style.transform.scale.truncate(),
+                    ..object_fit.clone()
+                });
+            }
+            if style.cursor.is_set() {
+                world.entity_mut(entity).insert(style.cursor.clone());
+            }
+            if style.scroll.is_set() {
+                world.entity_mut(entity).insert(style.scroll.clone());
+            }
+            if style.inheritable_text_style.is_set() {
+                world
+                    .entity_mut(entity)
+                    .insert(style.inheritable_text_style.clone());
+            }
+        }
+        entity
+    }
+
+    fn spawn_entity(&self, world: &mut World) -> Entity {
         match self {
             BevyTemplateNode::Node { style, children } => {
                 let children = children
@@ -374,6 +780,7 @@ impl BevyTemplateNode {
             }
             BevyTemplateNode::TextNode {
                 text,
+                text_transform,
                 style,
                 children,
             } => {
@@ -397,6 +804,7 @@ impl BevyTemplateNode {
                             ..default()
                         },
                         style.outline,
+                        text_transform.clone(),
                     ))
                     .push_children(&children)
                     .id()
@@ -443,40 +851,62 @@ impl BevyTemplateNode {
 fn parse_template_attributes(
     attributes: &[TemplateAttribute],
     background_color: Color,
+    theme: &DioxusTheme,
     asset_server: &AssetServer,
-) -> (StyleComponents, Text, UiImage) {
+) -> (StyleComponents, Text, TextTransform, UiImage) {
     let mut style = StyleComponents {
         background_color: BackgroundColor(background_color),
         ..default()
     };
     let mut text = Text::from_section("", TextStyle::default());
+    let mut text_transform = TextTransform::default();
     let mut image = UiImage::default();
+    let mut classes = None;
+    let mut inline_attributes = HashMap::new();
+    let mut has_dynamic_attributes = false;
     for attribute in attributes {
+        has_dynamic_attributes |= matches!(attribute, TemplateAttribute::Dynamic { .. });
         if let TemplateAttribute::Static {
             name,
             value,
             namespace: _,
         } = attribute
         {
-            set_attribute(
+            if *name == "class" {
+                classes = Some((*value).to_owned());
+                continue;
+            }
+            inline_attributes.insert((*name).to_owned(), (*value).to_owned());
+
+            if is_theme_value(value) {
+                style
+                    .themed_attributes
+                    .0
+                    .insert((*name).to_owned(), (*value).to_owned());
+            }
+            style.set_attribute(
                 name,
-                value,
-                &mut style.style,
-                &mut style.border_color,
-                &mut style.outline,
-                &mut style.background_color,
-                &mut style.transform,
-                &mut style.visibility,
-                &mut style.z_index,
+                theme.resolve(value),
                 Some(&mut text),
+                Some(&mut text_transform),
                 Some(&mut image),
                 asset_server,
             );
         }
     }
-    (style, text, image)
+    // A dynamic attribute may be a `class`, which must not override the static inline attributes
+    if classes.is_some() || has_dynamic_attributes {
+        style.classes = Some(Classes {
+            classes: classes.unwrap_or_default(),
+            inline_attributes,
+        });
+    }
+    (style, text, text_transform, image)
 }
 
+/// The components an element's attributes are applied to. They start from Bevy's defaults, so an
+/// element without attributes lays out like a plain `NodeBundle`, e.g. the children of a column
+/// stretch to its full width unless its `align_items` says otherwise, as in CSS.
 #[derive(Default)]
 struct StyleComponents {
     style: Style,
@@ -486,4 +916,87 @@ struct StyleComponents {
     transform: Transform,
     visibility: Visibility,
     z_index: ZIndex,
+    themed_attributes: ThemedAttributes,
+    classes: Option<Classes>,
+    accessibility_attributes: AccessibilityAttributes,
+    interaction_background_colors: Option<InteractionBackgroundColors>,
+    text_shadow: Option<TextShadow>,
+    object_fit: Option<ObjectFit>,
+    cursor: UiCursor,
+    drag_data: Option<DragData>,
+    sticky: Option<Sticky>,
+    scroll: Scroll,
+    inheritable_text_style: InheritableTextStyle,
+}
+
+impl StyleComponents {
+    fn set_attribute(
+        &mut self,
+        name: &str,
+        value: &str,
+        text: Option<&mut Text>,
+        text_transform: Option<&mut TextTransform>,
+        image: Option<&mut UiImage>,
+        asset_server: &AssetServer,
+    ) {
+        if is_accessibility_attribute(name) {
+            self.accessibility_attributes
+                .set_attribute(name, Some(value));
+            return;
+        }
+        if is_interaction_attribute(name) {
+            self.interaction_background_colors
+                .get_or_insert_with(InteractionBackgroundColors::default)
+                .set_attribute(name, Some(value));
+            return;
+        }
+        if name == "text_shadow" {
+            self.text_shadow = Some(TextShadow::parse(value));
+            return;
+        }
+        if name == "object_fit" {
+            self.object_fit = Some(ObjectFit::parse(value));
+            return;
+        }
+        if is_cursor_attribute(name) {
+            self.cursor.set_attribute(name, Some(value), asset_server);
+            return;
+        }
+        if name == "drag_data" {
+            self.drag_data = Some(DragData(value.to_owned()));
+            return;
+        }
+        if name == "sticky" {
+            self.sticky = parse_sticky(value);
+            return;
+        }
+        if is_scrollbar_attribute(name) {
+            self.scroll.set_attribute(name, Some(value));
+            return;
+        }
+        if is_overflow_attribute(name) {
+            self.scroll.set_attribute(name, Some(value));
+        }
+        if is_inheritable_text_attribute(name) {
+            self.inheritable_text_style.set_attribute(name, Some(value));
+            if text.is_none() {
+                return;
+            }
+        }
+        set_attribute(
+            name,
+            value,
+            &mut self.style,
+            &mut self.border_color,
+            &mut self.outline,
+            &mut self.background_color,
+            &mut self.transform,
+            &mut self.visibility,
+            &mut self.z_index,
+            text,
+            text_transform,
+            image,
+            asset_server,
+        );
+    }
 }
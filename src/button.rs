@@ -0,0 +1,64 @@
+use crate::{
+    colors::{lighten, to_hex},
+    parse_attributes::parse_color,
+    prelude::*,
+};
+
+/// A clickable node whose background color follows its hover and click state.
+///
+/// `onclick` fires for every button, `onclick_secondary` and `onclick_middle` only for theirs.
+///
+/// Colors default to `theme.button`, `theme.button_hover` and `theme.button_click`. With only
+/// `base_color` set, the hover and click colors are lightened versions of it.
+///
+/// When glob importing `bevy::prelude::*` too, import this explicitly to shadow
+/// `bevy::ui::widget::Button`.
+#[allow(non_snake_case)]
+pub fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element<'a> {
+    let theme = use_resource_expect::<DioxusTheme>(cx);
+    let base_color = cx
+        .props
+        .base_color
+        .map(|color| parse_color(theme.resolve(color)));
+    let hover_color = match (cx.props.hover_color, base_color) {
+        (Some(color), _) => color.to_owned(),
+        (None, Some(base_color)) => to_hex(lighten(base_color, 0.1)),
+        (None, None) => "theme.button_hover".to_owned(),
+    };
+    let click_color = match (cx.props.click_color, base_color) {
+        (Some(color), _) => color.to_owned(),
+        (None, Some(base_color)) => to_hex(lighten(base_color, 0.2)),
+        (None, None) => "theme.button_click".to_owned(),
+    };
+
+    render! {
+        node {
+            onclick: move |event| cx.props.onclick.call(event),
+            onclick_secondary: move |event| if let Some(onclick_secondary) = &cx.props.onclick_secondary {
+                onclick_secondary.call(event);
+            },
+            onclick_middle: move |event| if let Some(onclick_middle) = &cx.props.onclick_middle {
+                onclick_middle.call(event);
+            },
+            class: cx.props.class.unwrap_or_default(),
+            padding: cx.props.padding.unwrap_or("theme.md"),
+            background_color: cx.props.base_color.unwrap_or("theme.button"),
+            hover_background_color: "{hover_color}",
+            active_background_color: "{click_color}",
+            &cx.props.children
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct ButtonProps<'a> {
+    onclick: EventHandler<'a, DioxusEvent<PointerButton>>,
+    onclick_secondary: Option<EventHandler<'a, DioxusEvent<PointerButton>>>,
+    onclick_middle: Option<EventHandler<'a, DioxusEvent<PointerButton>>>,
+    base_color: Option<&'a str>,
+    click_color: Option<&'a str>,
+    hover_color: Option<&'a str>,
+    padding: Option<&'a str>,
+    class: Option<&'a str>,
+    children: Element<'a>,
+}
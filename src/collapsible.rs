@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+/// A header that shows or hides its children when clicked.
+///
+/// Pass `open` to control the state from the parent, updating it from `onchange`. Without it, the
+/// section starts closed and tracks its own state. Collapsed children stay mounted with
+/// `display: none`, so their state survives.
+#[allow(non_snake_case)]
+pub fn Collapsible<'a>(cx: Scope<'a, CollapsibleProps<'a>>) -> Element<'a> {
+    let uncontrolled_open = use_state(cx, || false);
+    let open = cx.props.open.unwrap_or(**uncontrolled_open);
+    let (toggle, display) = if open { ("-", "flex") } else { ("+", "none") };
+
+    render! {
+        node {
+            flex_direction: "column",
+            node {
+                column_gap: "6",
+                align_items: "baseline",
+                onclick: move |_| {
+                    uncontrolled_open.set(!open);
+                    if let Some(onchange) = &cx.props.onchange {
+                        onchange.call(!open);
+                    }
+                },
+                text { text: toggle, text_color: "theme.text_muted" }
+                text { text: cx.props.title }
+            }
+            node {
+                flex_direction: "column",
+                display: display,
+                &cx.props.children
+            }
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct CollapsibleProps<'a> {
+    title: &'a str,
+    open: Option<bool>,
+    onchange: Option<EventHandler<'a, bool>>,
+    children: Element<'a>,
+}
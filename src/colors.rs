@@ -1,5 +1,12 @@
 // Tailwind v3.4.0 https://tailwindcss.com/docs/customizing-colors
 
+use bevy::render::color::Color;
+use dioxus::core::{
+    exports::bumpalo::{collections::String as BumpString, Bump},
+    AttributeValue, IntoAttributeValue,
+};
+use std::fmt::Write;
+
 pub const BLACK: &str = "#000000";
 pub const WHITE: &str = "#ffffff";
 pub const TRANSPARENT: &str = "#00000000";
@@ -267,3 +274,97 @@ pub const ROSE_700: &str = "#be123c";
 pub const ROSE_800: &str = "#9f1239";
 pub const ROSE_900: &str = "#881337";
 pub const ROSE_950: &str = "#4c0519";
+
+// ----------------------------------------------------------------------------
+
+#[rustfmt::skip]
+const PALETTE: [[&str; 11]; 22] = [
+    [SLATE_50, SLATE_100, SLATE_200, SLATE_300, SLATE_400, SLATE_500, SLATE_600, SLATE_700, SLATE_800, SLATE_900, SLATE_950],
+    [GRAY_50, GRAY_100, GRAY_200, GRAY_300, GRAY_400, GRAY_500, GRAY_600, GRAY_700, GRAY_800, GRAY_900, GRAY_950],
+    [ZINC_50, ZINC_100, ZINC_200, ZINC_300, ZINC_400, ZINC_500, ZINC_600, ZINC_700, ZINC_800, ZINC_900, ZINC_950],
+    [NEUTRAL_50, NEUTRAL_100, NEUTRAL_200, NEUTRAL_300, NEUTRAL_400, NEUTRAL_500, NEUTRAL_600, NEUTRAL_700, NEUTRAL_800, NEUTRAL_900, NEUTRAL_950],
+    [STONE_50, STONE_100, STONE_200, STONE_300, STONE_400, STONE_500, STONE_600, STONE_700, STONE_800, STONE_900, STONE_950],
+    [RED_50, RED_100, RED_200, RED_300, RED_400, RED_500, RED_600, RED_700, RED_800, RED_900, RED_950],
+    [ORANGE_50, ORANGE_100, ORANGE_200, ORANGE_300, ORANGE_400, ORANGE_500, ORANGE_600, ORANGE_700, ORANGE_800, ORANGE_900, ORANGE_950],
+    [AMBER_50, AMBER_100, AMBER_200, AMBER_300, AMBER_400, AMBER_500, AMBER_600, AMBER_700, AMBER_800, AMBER_900, AMBER_950],
+    [YELLOW_50, YELLOW_100, YELLOW_200, YELLOW_300, YELLOW_400, YELLOW_500, YELLOW_600, YELLOW_700, YELLOW_800, YELLOW_900, YELLOW_950],
+    [LIME_50, LIME_100, LIME_200, LIME_300, LIME_400, LIME_500, LIME_600, LIME_700, LIME_800, LIME_900, LIME_950],
+    [GREEN_50, GREEN_100, GREEN_200, GREEN_300, GREEN_400, GREEN_500, GREEN_600, GREEN_700, GREEN_800, GREEN_900, GREEN_950],
+    [EMERALD_50, EMERALD_100, EMERALD_200, EMERALD_300, EMERALD_400, EMERALD_500, EMERALD_600, EMERALD_700, EMERALD_800, EMERALD_900, EMERALD_950],
+    [TEAL_50, TEAL_100, TEAL_200, TEAL_300, TEAL_400, TEAL_500, TEAL_600, TEAL_700, TEAL_800, TEAL_900, TEAL_950],
+    [CYAN_50, CYAN_100, CYAN_200, CYAN_300, CYAN_400, CYAN_500, CYAN_600, CYAN_700, CYAN_800, CYAN_900, CYAN_950],
+    [SKY_50, SKY_100, SKY_200, SKY_300, SKY_400, SKY_500, SKY_600, SKY_700, SKY_800, SKY_900, SKY_950],
+    [BLUE_50, BLUE_100, BLUE_200, BLUE_300, BLUE_400, BLUE_500, BLUE_600, BLUE_700, BLUE_800, BLUE_900, BLUE_950],
+    [INDIGO_50, INDIGO_100, INDIGO_200, INDIGO_300, INDIGO_400, INDIGO_500, INDIGO_600, INDIGO_700, INDIGO_800, INDIGO_900, INDIGO_950],
+    [VIOLET_50, VIOLET_100, VIOLET_200, VIOLET_300, VIOLET_400, VIOLET_500, VIOLET_600, VIOLET_700, VIOLET_800, VIOLET_900, VIOLET_950],
+    [PURPLE_50, PURPLE_100, PURPLE_200, PURPLE_300, PURPLE_400, PURPLE_500, PURPLE_600, PURPLE_700, PURPLE_800, PURPLE_900, PURPLE_950],
+    [FUCHSIA_50, FUCHSIA_100, FUCHSIA_200, FUCHSIA_300, FUCHSIA_400, FUCHSIA_500, FUCHSIA_600, FUCHSIA_700, FUCHSIA_800, FUCHSIA_900, FUCHSIA_950],
+    [PINK_50, PINK_100, PINK_200, PINK_300, PINK_400, PINK_500, PINK_600, PINK_700, PINK_800, PINK_900, PINK_950],
+    [ROSE_50, ROSE_100, ROSE_200, ROSE_300, ROSE_400, ROSE_500, ROSE_600, ROSE_700, ROSE_800, ROSE_900, ROSE_950],
+];
+
+/// Moves a palette color `steps` shades along its hue, e.g. `shade(VIOLET_500, -1)` is
+/// `VIOLET_400`. Stops at the lightest and darkest shades, and returns colors that aren't in the
+/// palette unchanged.
+pub fn shade(color: &'static str, steps: i32) -> &'static str {
+    PALETTE
+        .iter()
+        .find_map(|shades| {
+            let index = shades.iter().position(|shade| *shade == color)? as i32;
+            Some(shades[(index + steps).clamp(0, shades.len() as i32 - 1) as usize])
+        })
+        .unwrap_or(color)
+}
+
+/// Raises the HSL lightness of `color` by `amount` (from `0.0` to `1.0`).
+pub fn lighten(color: Color, amount: f32) -> Color {
+    let Color::Hsla {
+        hue,
+        saturation,
+        lightness,
+        alpha,
+    } = color.as_hsla()
+    else {
+        unreachable!()
+    };
+    Color::hsla(hue, saturation, (lightness + amount).clamp(0.0, 1.0), alpha)
+}
+
+/// Lowers the HSL lightness of `color` by `amount` (from `0.0` to `1.0`).
+pub fn darken(color: Color, amount: f32) -> Color {
+    lighten(color, -amount)
+}
+
+pub fn with_alpha(color: Color, alpha: f32) -> Color {
+    color.with_a(alpha)
+}
+
+/// Formats `color` as an `#rrggbbaa` string, to pass derived colors to color attributes.
+pub fn to_hex(color: Color) -> String {
+    let [r, g, b, a] = color.as_rgba_u8();
+    format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+}
+
+/// Passes a [`Color`] to a color attribute, e.g. `background_color: UiColor(color)`, for colors
+/// computed at runtime or kept as `Color` constants.
+///
+/// Attribute values are strings so themes, classes and hot reloading can treat every color alike,
+/// so this formats the color like [`to_hex`], but straight into the render's arena without an
+/// intermediate `String`. String literals and palette constants keep working as before.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct UiColor(pub Color);
+
+impl From<Color> for UiColor {
+    fn from(color: Color) -> Self {
+        Self(color)
+    }
+}
+
+impl<'a> IntoAttributeValue<'a> for UiColor {
+    fn into_value(self, bump: &'a Bump) -> AttributeValue<'a> {
+        let [r, g, b, a] = self.0.as_rgba_u8();
+        let mut hex = BumpString::with_capacity_in(9, bump);
+        write!(hex, "#{r:02x}{g:02x}{b:02x}{a:02x}").unwrap();
+        AttributeValue::Text(hex.into_bump_str())
+    }
+}
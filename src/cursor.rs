@@ -0,0 +1,242 @@
+use bevy::{
+    asset::{AssetServer, Assets, Handle},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::With,
+        system::{Commands, Local, Query, Res},
+        world::EntityWorldMut,
+    },
+    hierarchy::Parent,
+    log::warn,
+    math::Vec2,
+    prelude::default,
+    render::{texture::Image, view::Visibility},
+    ui::{node_bundles::ImageBundle, PositionType, Style, UiImage, Val, ZIndex},
+    window::{CursorIcon, PrimaryWindow, Window},
+};
+use bevy_mod_picking::{focus::HoverMap, pointer::PointerId, prelude::Pickable};
+
+/// The mouse cursor shown over a node with a `cursor` attribute, and over its descendants that
+/// don't have their own.
+///
+/// `cursor` takes a CSS cursor name like `pointer`, `text` or `grab`, with underscores instead of
+/// dashes, or `image:` followed by an asset path for a custom cursor image. Images are drawn as a
+/// software cursor above all UI, with the point at `cursor_hotspot` (`"x y"` in pixels from the
+/// top left, default `"0 0"`) under the mouse. Until the image is loaded, or without a mouse, the
+/// standard cursor shows instead.
+#[derive(Component, Clone, Default)]
+pub(crate) struct UiCursor {
+    kind: Option<CursorKind>,
+    hotspot: Vec2,
+}
+
+#[derive(Clone, PartialEq)]
+enum CursorKind {
+    Icon(CursorIcon),
+    Image(Handle<Image>),
+}
+
+impl UiCursor {
+    pub fn set_attribute(&mut self, name: &str, value: Option<&str>, asset_server: &AssetServer) {
+        match (name, value) {
+            ("cursor", Some(value)) => self.kind = Some(parse_cursor(value, asset_server)),
+            ("cursor", None) => self.kind = None,
+            ("cursor_hotspot", Some(value)) => self.hotspot = parse_hotspot(value),
+            ("cursor_hotspot", None) => self.hotspot = Vec2::ZERO,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.kind.is_some() || self.hotspot != Vec2::ZERO
+    }
+}
+
+pub(crate) fn is_cursor_attribute(name: &str) -> bool {
+    matches!(name, "cursor" | "cursor_hotspot")
+}
+
+pub(crate) fn set_cursor_attribute(
+    name: &str,
+    value: Option<&str>,
+    entity: &mut EntityWorldMut,
+    asset_server: &AssetServer,
+) {
+    let mut cursor = entity.get::<UiCursor>().cloned().unwrap_or_default();
+    cursor.set_attribute(name, value, asset_server);
+    if cursor.is_set() {
+        entity.insert(cursor);
+    } else {
+        entity.remove::<UiCursor>();
+    }
+}
+
+fn parse_cursor(value: &str, asset_server: &AssetServer) -> CursorKind {
+    if let Some(path) = value.strip_prefix("image:") {
+        return CursorKind::Image(asset_server.load(path.to_owned()));
+    }
+    CursorKind::Icon(match value {
+        "default" | "auto" => CursorIcon::Default,
+        "pointer" => CursorIcon::Hand,
+        "text" => CursorIcon::Text,
+        "vertical_text" => CursorIcon::VerticalText,
+        "move" => CursorIcon::Move,
+        "all_scroll" => CursorIcon::AllScroll,
+        "grab" => CursorIcon::Grab,
+        "grabbing" => CursorIcon::Grabbing,
+        "crosshair" => CursorIcon::Crosshair,
+        "cell" => CursorIcon::Cell,
+        "wait" => CursorIcon::Wait,
+        "progress" => CursorIcon::Progress,
+        "help" => CursorIcon::Help,
+        "context_menu" => CursorIcon::ContextMenu,
+        "not_allowed" => CursorIcon::NotAllowed,
+        "no_drop" => CursorIcon::NoDrop,
+        "copy" => CursorIcon::Copy,
+        "alias" => CursorIcon::Alias,
+        "zoom_in" => CursorIcon::ZoomIn,
+        "zoom_out" => CursorIcon::ZoomOut,
+        "col_resize" => CursorIcon::ColResize,
+        "row_resize" => CursorIcon::RowResize,
+        "ew_resize" => CursorIcon::EwResize,
+        "ns_resize" => CursorIcon::NsResize,
+        "nesw_resize" => CursorIcon::NeswResize,
+        "nwse_resize" => CursorIcon::NwseResize,
+        _ => {
+            warn!("Encountered unknown bevy_dioxus cursor `{value}`, using `default`.");
+            CursorIcon::Default
+        }
+    })
+}
+
+fn parse_hotspot(value: &str) -> Vec2 {
+    let parsed = match value.split_whitespace().collect::<Vec<_>>()[..] {
+        [x, y] => x.parse().ok().zip(y.parse().ok()),
+        _ => None,
+    };
+    parsed.map(Vec2::from).unwrap_or_else(|| {
+        warn!("Encountered invalid bevy_dioxus cursor_hotspot `{value}`, expected `\"x y\"`.");
+        Vec2::ZERO
+    })
+}
+
+/// The cursor last applied to the window, so the window's cursor is only touched when it changes
+/// and the app's own cursor is left alone while the mouse isn't over a node with a `cursor`.
+#[derive(Default, PartialEq)]
+pub struct AppliedCursor(Option<CursorKind>);
+
+/// Shows the cursor of the node under the mouse, drawing image cursors with a software cursor
+/// node.
+#[allow(clippy::too_many_arguments)]
+pub fn update_cursor(
+    hover_map: Res<HoverMap>,
+    cursors: Query<&UiCursor>,
+    parents: Query<&Parent>,
+    images: Option<Res<Assets<Image>>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut software_cursors: Query<(&mut Style, &mut UiImage, &mut Visibility)>,
+    mut software_cursor: Local<Option<Entity>>,
+    mut applied: Local<AppliedCursor>,
+    mut commands: Commands,
+) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+
+    let cursor = hover_map
+        .get(&PointerId::Mouse)
+        .and_then(|hovered| {
+            hovered
+                .keys()
+                .find_map(|entity| cursor_ancestor(*entity, &cursors, &parents))
+        })
+        .and_then(|cursor| Some((cursor.kind.clone()?, cursor.hotspot)));
+    let position = window.cursor_position();
+    // Fall back to the standard cursor until the image is loaded, and without a mouse
+    let (kind, hotspot) = match cursor {
+        Some((CursorKind::Image(image), hotspot))
+            if position.is_some()
+                && images
+                    .as_ref()
+                    .is_some_and(|images| images.contains(&image)) =>
+        {
+            (Some(CursorKind::Image(image)), hotspot)
+        }
+        Some((CursorKind::Image(_), _)) => {
+            (Some(CursorKind::Icon(CursorIcon::Default)), Vec2::ZERO)
+        }
+        Some((kind, hotspot)) => (Some(kind), hotspot),
+        None => (None, Vec2::ZERO),
+    };
+
+    let software_cursor_entity = *software_cursor.get_or_insert_with(|| {
+        commands
+            .spawn((
+                ImageBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    z_index: ZIndex::Global(i32::MAX),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                Pickable::IGNORE,
+            ))
+            .id()
+    });
+    if let (Some(CursorKind::Image(_)), Some(position), Ok((mut style, ..))) = (
+        &kind,
+        position,
+        software_cursors.get_mut(software_cursor_entity),
+    ) {
+        style.left = Val::Px(position.x - hotspot.x);
+        style.top = Val::Px(position.y - hotspot.y);
+    }
+
+    let kind = AppliedCursor(kind);
+    if *applied == kind {
+        return;
+    }
+    let software_cursor = software_cursors.get_mut(software_cursor_entity).ok();
+    match &kind.0 {
+        Some(CursorKind::Image(image)) => {
+            // Spawned by the commands above, try again next frame
+            let Some((_, mut ui_image, mut visibility)) = software_cursor else {
+                return;
+            };
+            window.cursor.visible = false;
+            ui_image.texture = image.clone();
+            *visibility = Visibility::Inherited;
+        }
+        Some(CursorKind::Icon(icon)) => {
+            window.cursor.icon = *icon;
+            window.cursor.visible = true;
+            if let Some((_, _, mut visibility)) = software_cursor {
+                *visibility = Visibility::Hidden;
+            }
+        }
+        None => {
+            window.cursor.icon = CursorIcon::Default;
+            window.cursor.visible = true;
+            if let Some((_, _, mut visibility)) = software_cursor {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+    *applied = kind;
+}
+
+fn cursor_ancestor<'a>(
+    mut entity: Entity,
+    cursors: &'a Query<&UiCursor>,
+    parents: &Query<&Parent>,
+) -> Option<&'a UiCursor> {
+    loop {
+        if let Ok(cursor) = cursors.get(entity) {
+            return Some(cursor);
+        }
+        entity = parents.get(entity).ok()?.get();
+    }
+}
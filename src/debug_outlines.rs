@@ -0,0 +1,34 @@
+use crate::{apply_mutations::DioxusNode, DioxusUiSettings};
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{With, Without},
+        system::{Commands, Query, Res},
+    },
+    render::color::Color,
+    ui::{Outline, Val},
+};
+
+/// The outline an element had before `DioxusUiSettings::debug_outlines` replaced it.
+#[derive(Component)]
+pub struct DebugOutline(Outline);
+
+pub fn apply_debug_outlines(
+    settings: Res<DioxusUiSettings>,
+    mut elements: Query<(Entity, &mut Outline), (With<DioxusNode>, Without<DebugOutline>)>,
+    mut outlined_elements: Query<(Entity, &mut Outline, &DebugOutline)>,
+    mut commands: Commands,
+) {
+    if settings.debug_outlines {
+        for (entity, mut outline) in &mut elements {
+            commands.entity(entity).insert(DebugOutline(*outline));
+            *outline = Outline::new(Val::Px(1.0), Val::Px(0.0), Color::FUCHSIA);
+        }
+    } else if settings.is_changed() {
+        for (entity, mut outline, debug_outline) in &mut outlined_elements {
+            *outline = debug_outline.0;
+            commands.entity(entity).remove::<DebugOutline>();
+        }
+    }
+}
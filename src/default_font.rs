@@ -0,0 +1,39 @@
+use crate::{apply_mutations::DioxusNode, DioxusUiSettings};
+use bevy::{
+    asset::{AssetServer, Handle},
+    ecs::{
+        change_detection::DetectChanges,
+        query::With,
+        system::{Local, Query, Res},
+    },
+    text::{Font, Text},
+};
+
+pub fn apply_default_font(
+    settings: Res<DioxusUiSettings>,
+    asset_server: Res<AssetServer>,
+    mut default_font: Local<Handle<Font>>,
+    mut texts: Query<&mut Text, With<DioxusNode>>,
+) {
+    // Loaded here rather than when the plugin is built, which may be before the `AssetServer` is
+    let previous_font = settings.is_changed().then(|| {
+        let font = match &settings.default_font {
+            Some(path) => asset_server.load(path.clone()),
+            None => Handle::default(),
+        };
+        std::mem::replace(&mut *default_font, font)
+    });
+    if previous_font.is_none() && *default_font == Handle::default() {
+        return;
+    }
+
+    for mut text in &mut texts {
+        // Text without a `font` attribute still has the default handle, or the previous default
+        let font = &text.sections[0].style.font;
+        let uses_default = *font == Handle::default() || Some(font) == previous_font.as_ref();
+        let outdated = text.is_changed() || previous_font.is_some();
+        if uses_default && outdated && *font != *default_font {
+            text.sections[0].style.font = default_font.clone();
+        }
+    }
+}
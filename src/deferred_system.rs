@@ -1,15 +1,43 @@
 use crate::ecs_hooks::EcsContext;
-use bevy::ecs::system::{IntoSystem, Resource, System};
-use dioxus::core::ScopeState;
+use bevy::{
+    ecs::{
+        entity::Entity,
+        schedule::ScheduleLabel,
+        system::{IntoSystem, Resource, System, SystemId},
+        world::World,
+    },
+    hierarchy::DespawnRecursiveExt,
+    log::warn,
+};
+use dioxus::{core::ScopeState, hooks::use_on_destroy};
+use std::{cell::RefCell, rc::Rc, sync::Mutex};
+
+/// Systems scheduled by components, with the entity of the root they were scheduled from.
+type RunQueue = Vec<(Entity, Box<dyn System<In = (), Out = ()>>)>;
 
 #[derive(Resource, Default)]
 pub struct DeferredSystemRunQueue {
-    pub run_queue: Box<Vec<Box<dyn System<In = (), Out = ()>>>>,
+    // Behind a mutex so schedulers can be created from a shared `&World`, which components may
+    // already be borrowing through `use_world`
+    pub run_queue: Box<Mutex<RunQueue>>,
+}
+
+impl DeferredSystemRunQueue {
+    /// Discards the systems scheduled from a root that's being torn down, so they don't act on
+    /// state that's gone. Systems its components schedule while being dropped, like
+    /// `use_world_effect` cleanups, are scheduled after this and still run.
+    pub(crate) fn cancel_root_systems(&mut self, root_entity: Entity) {
+        self.run_queue
+            .get_mut()
+            .expect("Lock poisoned")
+            .retain(|(entity, _)| *entity != root_entity);
+    }
 }
 
 #[derive(Clone, Copy)]
 pub struct DeferredSystemScheduler {
-    run_queue: *mut Vec<Box<dyn System<In = (), Out = ()>>>,
+    run_queue: *const Mutex<RunQueue>,
+    root_entity: Entity,
 }
 
 impl DeferredSystemScheduler {
@@ -18,7 +46,18 @@ impl DeferredSystemScheduler {
         S: IntoSystem<(), (), M> + 'static,
         M: 'static,
     {
-        unsafe { &mut *self.run_queue }.push(Box::new(S::into_system(system)));
+        unsafe { &*self.run_queue }
+            .lock()
+            .expect("Lock poisoned")
+            .push((self.root_entity, Box::new(S::into_system(system))));
+    }
+
+    pub fn despawn_recursive(&self, entity: Entity) {
+        self.schedule(move |world: &mut World| {
+            if let Some(entity) = world.get_entity_mut(entity) {
+                entity.despawn_recursive();
+            }
+        });
     }
 }
 
@@ -27,10 +66,123 @@ unsafe impl Sync for DeferredSystemScheduler {}
 
 pub fn use_system_scheduler(cx: &ScopeState) -> DeferredSystemScheduler {
     DeferredSystemScheduler {
-        run_queue: Box::as_mut(
-            &mut EcsContext::get_world(cx)
-                .resource_mut::<DeferredSystemRunQueue>()
+        run_queue: Box::as_ref(
+            &EcsContext::get_world_ref(cx)
+                .resource::<DeferredSystemRunQueue>()
                 .run_queue,
         ),
+        root_entity: EcsContext::get_root_entity(cx),
+    }
+}
+
+/// Runs one-shot systems during the next world-access phase, so game logic can live in systems
+/// rather than closures passed to `use_system_scheduler`.
+#[derive(Clone, Copy)]
+pub struct DeferredSystemRunner {
+    scheduler: DeferredSystemScheduler,
+}
+
+impl DeferredSystemRunner {
+    /// Runs a system registered with `World::register_system`. Systems that were removed, or that
+    /// are already running, are skipped with a warning.
+    pub fn run(&self, system_id: SystemId) {
+        self.scheduler.schedule(move |world: &mut World| {
+            if let Err(error) = world.run_system(system_id) {
+                warn!("Can't run one-shot system from bevy_dioxus: {error}");
+            }
+        });
+    }
+
+    /// Runs `system` with `input` and passes its output to `on_output`, e.g. to write it into a
+    /// `use_state_sendable`. Bevy 0.12's `SystemId` can't take input or return output, so these
+    /// systems aren't registered and are initialized again on every run.
+    pub fn run_with_input<I, O, S, M>(
+        &self,
+        system: S,
+        input: I,
+        on_output: impl FnOnce(O) + Send + Sync + 'static,
+    ) where
+        I: Send + Sync + 'static,
+        O: 'static,
+        S: IntoSystem<I, O, M>,
+    {
+        let mut run = Some((S::into_system(system), input, on_output));
+        self.scheduler.schedule(move |world: &mut World| {
+            let Some((mut system, input, on_output)) = run.take() else {
+                return;
+            };
+            system.initialize(world);
+            let output = system.run(input, world);
+            system.apply_deferred(world);
+            on_output(output);
+        });
+    }
+}
+
+pub fn use_run_system(cx: &ScopeState) -> DeferredSystemRunner {
+    DeferredSystemRunner {
+        scheduler: use_system_scheduler(cx),
+    }
+}
+
+/// Runs schedules once during the next world-access phase, e.g. to single-step a paused
+/// simulation from a debugger's "Step" button.
+#[derive(Clone, Copy)]
+pub struct DeferredScheduleRunner {
+    scheduler: DeferredSystemScheduler,
+}
+
+impl DeferredScheduleRunner {
+    /// Schedules that are already running can't be run again, including `Last` and `Main` which
+    /// bevy_dioxus runs from. Those are skipped with a warning.
+    pub fn run(&self, label: impl ScheduleLabel + Clone) {
+        self.scheduler.schedule(move |world: &mut World| {
+            if let Err(error) = world.try_run_schedule(label.clone()) {
+                warn!("Can't run schedule from bevy_dioxus, it may already be running: {error}");
+            }
+        });
     }
 }
+
+pub fn use_run_schedule(cx: &ScopeState) -> DeferredScheduleRunner {
+    DeferredScheduleRunner {
+        scheduler: use_system_scheduler(cx),
+    }
+}
+
+/// Runs `setup` on mount and whenever `dependencies` change, and the cleanup it returns before the
+/// next `setup` or on unmount. Both get a scheduler for deferred world access, e.g. to spawn a
+/// preview entity while a menu is open and despawn it once closed.
+pub fn use_world_effect<D, C>(
+    cx: &ScopeState,
+    dependencies: D,
+    setup: impl FnOnce(DeferredSystemScheduler) -> C,
+) where
+    D: PartialEq + 'static,
+    C: FnOnce(DeferredSystemScheduler) + 'static,
+{
+    let scheduler = use_system_scheduler(cx);
+    let (previous_dependencies, cleanup) = cx.use_hook(|| {
+        (
+            None,
+            Rc::new(RefCell::new(
+                None::<Box<dyn FnOnce(DeferredSystemScheduler)>>,
+            )),
+        )
+    });
+
+    if previous_dependencies.as_ref() != Some(&dependencies) {
+        if let Some(cleanup) = cleanup.borrow_mut().take() {
+            cleanup(scheduler);
+        }
+        *cleanup.borrow_mut() = Some(Box::new(setup(scheduler)));
+        *previous_dependencies = Some(dependencies);
+    }
+
+    let cleanup = Rc::clone(cleanup);
+    use_on_destroy(cx, move || {
+        if let Some(cleanup) = cleanup.borrow_mut().take() {
+            cleanup(scheduler);
+        }
+    });
+}
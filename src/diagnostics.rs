@@ -0,0 +1,66 @@
+use crate::UiContext;
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticId, DiagnosticMeasurement, DiagnosticsStore},
+    ecs::{entity::Entity, world::World},
+    utils::Instant,
+};
+
+/// The number of Bevy entities every root manages, measured each tick, e.g. to spot an unbounded
+/// list. Each root also reports its own count under [`root_nodes_diagnostic_id`].
+pub const DIOXUS_NODES: DiagnosticId =
+    DiagnosticId::from_u128(0x5c1e_87d4_2a3b_4f0e_9d61_0b7a_c3f2_e815);
+
+const MAX_HISTORY_LENGTH: usize = 20;
+
+/// The id of the "dioxus/nodes/{root}" diagnostic, the number of Bevy entities the root on
+/// `root_entity` manages.
+pub fn root_nodes_diagnostic_id(root_entity: Entity) -> DiagnosticId {
+    DiagnosticId::from_u128(DIOXUS_NODES.0.as_u128() ^ root_entity.to_bits() as u128)
+}
+
+pub(crate) fn nodes_diagnostic() -> Diagnostic {
+    Diagnostic::new(DIOXUS_NODES, "dioxus/nodes", MAX_HISTORY_LENGTH)
+}
+
+/// Measures the entities each root's element map tracks, which rendering keeps up to date.
+pub(crate) fn measure_nodes(world: &mut World) {
+    let counts = world
+        .non_send_resource::<UiContext>()
+        .roots
+        .iter()
+        .map(|((root_entity, _), ui_root)| {
+            (*root_entity, ui_root.element_id_to_bevy_ui_entity.len())
+        })
+        .collect::<Vec<_>>();
+    let mut diagnostics = world.resource_mut::<DiagnosticsStore>();
+
+    let time = Instant::now();
+    let mut total = 0;
+    for (root_entity, count) in counts {
+        let id = root_nodes_diagnostic_id(root_entity);
+        if diagnostics.get(id).is_none() {
+            diagnostics.add(Diagnostic::new(
+                id,
+                format!("dioxus/nodes/{root_entity:?}"),
+                MAX_HISTORY_LENGTH,
+            ));
+        }
+        add_measurement(&mut diagnostics, id, time, count);
+        total += count;
+    }
+    add_measurement(&mut diagnostics, DIOXUS_NODES, time, total);
+}
+
+fn add_measurement(
+    diagnostics: &mut DiagnosticsStore,
+    id: DiagnosticId,
+    time: Instant,
+    count: usize,
+) {
+    if let Some(diagnostic) = diagnostics.get_mut(id) {
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time,
+            value: count as f64,
+        });
+    }
+}
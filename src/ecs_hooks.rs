@@ -1,19 +1,30 @@
-use crate::UiContext;
+use crate::{
+    deferred_system::{use_system_scheduler, DeferredSystemScheduler},
+    events::DioxusUiEvent,
+};
 use bevy::{
     ecs::{
         component::ComponentId,
-        event::{Event, EventIterator, Events, ManualEventReader},
-        query::{QueryState, ReadOnlyWorldQuery},
+        entity::Entity,
+        event::Events,
+        query::{QueryState, ROQueryItem, ReadOnlyWorldQuery, With},
         system::{Query, Resource},
         world::{unsafe_world_cell::UnsafeWorldCell, World},
     },
+    hierarchy::Children,
+    reflect::Reflect,
     utils::{HashMap, HashSet},
+    window::{PrimaryWindow, Window},
 };
 use dioxus::{
     core::{ScopeId, ScopeState},
     hooks::use_on_destroy,
 };
-use std::any::TypeId;
+use std::{
+    any::TypeId,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 #[derive(Default)]
 pub(crate) struct EcsSubscriptions {
@@ -21,16 +32,32 @@ pub(crate) struct EcsSubscriptions {
     #[allow(clippy::type_complexity)]
     pub events: Box<HashMap<TypeId, (Box<dyn Fn(&World) -> bool>, HashSet<ScopeId>)>>,
     pub world_and_queries: Box<HashSet<ScopeId>>,
+    #[allow(clippy::type_complexity)]
+    pub watched_values: Box<HashMap<ScopeId, Vec<Box<dyn FnMut(&World) -> bool>>>>,
+    pub custom_events: Box<HashMap<String, HashSet<ScopeId>>>,
 }
 
 #[derive(Clone)]
 pub(crate) struct EcsContext {
+    pub root_entity: Entity,
     pub world: *mut World,
+    pub subscriptions: *mut EcsSubscriptions,
 }
 
 impl EcsContext {
+    // Only use this for one-off initialization inside `use_hook`. Every read hook in a component
+    // shares the same world, so handing out `&mut World` on every render would alias the `&World`
+    // returned by earlier hooks.
     #[allow(clippy::mut_from_ref)]
     pub fn get_world(cx: &ScopeState) -> &mut World {
+        #[cfg(debug_assertions)]
+        WORLD_BORROWERS.with(|borrowers| {
+            assert!(
+                !borrowers.borrow().contains(&cx.scope_id()),
+                "bevy_dioxus hooks that initialize from the world (e.g. use_query, use_window) \
+                 must be called before use_world in the same component"
+            );
+        });
         unsafe {
             &mut *cx
                 .consume_context::<EcsContext>()
@@ -38,17 +65,60 @@ impl EcsContext {
                 .world
         }
     }
+
+    pub fn get_root_entity(cx: &ScopeState) -> Entity {
+        cx.consume_context::<EcsContext>()
+            .expect("Must be used from a dioxus component within a DioxusUiRoot bevy component")
+            .root_entity
+    }
+
+    pub fn get_world_ref(cx: &ScopeState) -> &World {
+        unsafe {
+            &*cx.consume_context::<EcsContext>()
+                .expect("Must be used from a dioxus component within a DioxusUiRoot bevy component")
+                .world
+        }
+    }
+
+    // The subscriptions of the UiRoot currently being rendered. Hooks must only keep pointers to
+    // the boxed fields, as the EcsSubscriptions itself moves between ticks.
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_subscriptions(cx: &ScopeState) -> &mut EcsSubscriptions {
+        unsafe {
+            &mut *cx
+                .consume_context::<EcsContext>()
+                .expect("Must be used from a dioxus component within a DioxusUiRoot bevy component")
+                .subscriptions
+        }
+    }
 }
 
-pub fn use_world(cx: &ScopeState) -> &World {
-    let world = EcsContext::get_world(cx);
+// Components that called `use_world` during the current render, to catch hooks creating a
+// `&mut World` while that `&World` is still borrowed.
+#[cfg(debug_assertions)]
+thread_local! {
+    static WORLD_BORROWERS: RefCell<HashSet<ScopeId>> = Default::default();
+}
+
+/// Forgets which components borrowed the world, called before each root renders.
+pub(crate) fn reset_world_borrowers() {
+    #[cfg(debug_assertions)]
+    WORLD_BORROWERS.with(|borrowers| borrowers.borrow_mut().clear());
+}
 
+/// Returns the world, re-rendering the component every tick.
+///
+/// The `&World` is valid for the whole render of the component, as nothing mutates the world
+/// while components render. Mutations scheduled through `use_system_scheduler`, `use_run_system`
+/// or `UseNodeRef::insert` are deferred to the next tick and never alias it. Hooks that need
+/// `&mut World` to initialize (`use_query`, `use_query_count`, `use_window`...)
+/// must be called before `use_world` in the same component, which debug builds assert.
+pub fn use_world(cx: &ScopeState) -> &World {
     let scope_id = cx.scope_id();
+    #[cfg(debug_assertions)]
+    WORLD_BORROWERS.with(|borrowers| borrowers.borrow_mut().insert(scope_id));
     let subscription_manager = *cx.use_hook(|| {
-        let subscription_manager = &mut world
-            .non_send_resource_mut::<UiContext>()
-            .subscriptions
-            .world_and_queries;
+        let subscription_manager = &mut EcsContext::get_subscriptions(cx).world_and_queries;
         subscription_manager.insert(scope_id);
         Box::as_mut(subscription_manager) as *mut HashSet<ScopeId>
     });
@@ -56,26 +126,75 @@ pub fn use_world(cx: &ScopeState) -> &World {
         unsafe { &mut *subscription_manager }.remove(&scope_id);
     });
 
-    world
+    EcsContext::get_world_ref(cx)
+}
+
+/// Runs `f` with the world and state created once by `init`, returning its result and
+/// re-rendering the component every tick.
+///
+/// An escape hatch for reads `use_world` can't express, like a `SystemState` with `Query`
+/// params, which `init` creates from `&mut World` on the first render and `f` reads with
+/// `SystemState::get`. Components render while others read the world, so `f` only gets `&World`.
+/// Schedule mutations with the scheduler it's given instead, they run after the render. Like the
+/// hooks that initialize from `&mut World`, call it before `use_world` and any other hook
+/// returning world data in the same component.
+pub fn use_world_scope<S: 'static, T>(
+    cx: &ScopeState,
+    init: impl FnOnce(&mut World) -> S,
+    f: impl FnOnce(&mut S, &World, DeferredSystemScheduler) -> T,
+) -> T {
+    let scheduler = use_system_scheduler(cx);
+    let scope_id = cx.scope_id();
+    let (state, subscription_manager) = cx.use_hook(|| {
+        let subscription_manager = &mut EcsContext::get_subscriptions(cx).world_and_queries;
+        subscription_manager.insert(scope_id);
+        (
+            init(EcsContext::get_world(cx)),
+            Box::as_mut(subscription_manager) as *mut HashSet<ScopeId>,
+        )
+    });
+    let subscription_manager = *subscription_manager;
+    use_on_destroy(cx, move || {
+        unsafe { &mut *subscription_manager }.remove(&scope_id);
+    });
+
+    f(state, EcsContext::get_world_ref(cx), scheduler)
 }
 
-pub fn use_resource<T: Resource>(cx: &ScopeState) -> &T {
-    let world = EcsContext::get_world(cx);
+/// Returns a resource, or `None` while it isn't inserted, re-rendering the component only on ticks
+/// where the resource has changed, been inserted or been removed.
+///
+/// Use [`use_resource_expect`] for resources that always exist, like ones the app inserts on
+/// startup.
+pub fn use_resource<T: Resource>(cx: &ScopeState) -> Option<&T> {
+    // Change detection doesn't report removals, or insertions of a resource that was never
+    // inserted before, as it has no id to subscribe to yet
+    use_watched_value(cx, |_| |world: &World| world.contains_resource::<T>());
 
-    let resource_id = world.components().resource_id::<T>().unwrap();
+    let world = EcsContext::get_world_ref(cx);
     let scope_id = cx.scope_id();
-    let subscription_manager = *cx.use_hook(|| {
-        let subscription_manager = &mut world
-            .non_send_resource_mut::<UiContext>()
-            .subscriptions
-            .resources;
-        subscription_manager
-            .entry(resource_id)
-            .or_default()
-            .insert(scope_id);
-        Box::as_mut(subscription_manager) as *mut HashMap<ComponentId, HashSet<ScopeId>>
+    let (subscribed_resource_id, subscription_manager) = cx.use_hook(|| {
+        let subscription_manager = &mut EcsContext::get_subscriptions(cx).resources;
+        (
+            Rc::new(Cell::new(None::<ComponentId>)),
+            Box::as_mut(subscription_manager) as *mut HashMap<ComponentId, HashSet<ScopeId>>,
+        )
     });
+    if subscribed_resource_id.get().is_none() {
+        if let Some(resource_id) = world.components().resource_id::<T>() {
+            unsafe { &mut **subscription_manager }
+                .entry(resource_id)
+                .or_default()
+                .insert(scope_id);
+            subscribed_resource_id.set(Some(resource_id));
+        }
+    }
+    let (subscribed_resource_id, subscription_manager) =
+        (Rc::clone(subscribed_resource_id), *subscription_manager);
     use_on_destroy(cx, move || {
+        let Some(resource_id) = subscribed_resource_id.get() else {
+            return;
+        };
         let subscription_manager = &mut unsafe { &mut *subscription_manager };
         let resource_subscriptions = subscription_manager.get_mut(&resource_id).unwrap();
         resource_subscriptions.remove(&scope_id);
@@ -84,29 +203,34 @@ pub fn use_resource<T: Resource>(cx: &ScopeState) -> &T {
         }
     });
 
-    world.resource()
+    world.get_resource()
+}
+
+/// Like [`use_resource`], but panics if the resource isn't inserted.
+pub fn use_resource_expect<T: Resource>(cx: &ScopeState) -> &T {
+    use_resource(cx).unwrap_or_else(|| {
+        panic!(
+            "Encountered missing bevy_dioxus resource `{}`.",
+            std::any::type_name::<T>()
+        )
+    })
 }
 
 pub fn use_query<Q>(cx: &ScopeState) -> UseQuery<'_, Q, ()>
 where
-    Q: ReadOnlyWorldQuery,
+    Q: ReadOnlyWorldQuery + 'static,
 {
     use_query_filtered(cx)
 }
 
 pub fn use_query_filtered<Q, F>(cx: &ScopeState) -> UseQuery<'_, Q, F>
 where
-    Q: ReadOnlyWorldQuery,
-    F: ReadOnlyWorldQuery,
+    Q: ReadOnlyWorldQuery + 'static,
+    F: ReadOnlyWorldQuery + 'static,
 {
-    let world = EcsContext::get_world(cx);
-
     let scope_id = cx.scope_id();
     let subscription_manager = *cx.use_hook(|| {
-        let subscription_manager = &mut world
-            .non_send_resource_mut::<UiContext>()
-            .subscriptions
-            .world_and_queries;
+        let subscription_manager = &mut EcsContext::get_subscriptions(cx).world_and_queries;
         subscription_manager.insert(scope_id);
         Box::as_mut(subscription_manager) as *mut HashSet<ScopeId>
     });
@@ -114,22 +238,193 @@ where
         unsafe { &mut *subscription_manager }.remove(&scope_id);
     });
 
+    let query_state = cx.use_hook(|| QueryState::<Q, F>::new(EcsContext::get_world(cx)));
+    let world = EcsContext::get_world_ref(cx);
+    query_state.update_archetypes(world);
+
     UseQuery {
-        query_state: QueryState::new(world),
-        world_cell: world.as_unsafe_world_cell(),
+        query_state,
+        world_cell: world.as_unsafe_world_cell_readonly(),
     }
 }
 
-pub fn use_event_reader<E: Event>(cx: &ScopeState) -> EventIterator<'_, E> {
-    // TODO: Register the subscription
+/// Returns the number of entities matching the query, re-rendering the component only on ticks
+/// where the count has changed.
+pub fn use_query_count<Q, F>(cx: &ScopeState) -> usize
+where
+    Q: ReadOnlyWorldQuery + 'static,
+    F: ReadOnlyWorldQuery + 'static,
+{
+    use_watched_value(cx, |cx| {
+        let mut query_state = QueryState::<Q, F>::new(EcsContext::get_world(cx));
+        move |world: &World| query_state.iter(world).count()
+    })
+}
+
+/// Returns the entities matching the query with the keys `key` computes for them, sorted by key
+/// and then entity, re-rendering the component only on ticks where the result has changed.
+///
+/// The keys are computed every tick, but only re-sorted when they or the entities change.
+pub fn use_query_sorted<Q, F, K>(
+    cx: &ScopeState,
+    key: impl Fn(ROQueryItem<'_, Q>) -> K + 'static,
+) -> Vec<(Entity, K)>
+where
+    Q: ReadOnlyWorldQuery + 'static,
+    F: ReadOnlyWorldQuery + 'static,
+    K: Ord + Clone + 'static,
+{
+    use_watched_value(cx, |cx| {
+        let mut query_state = QueryState::<(Entity, Q), F>::new(EcsContext::get_world(cx));
+        let mut unsorted = Vec::new();
+        let mut sorted = Vec::new();
+        move |world: &World| {
+            let keyed = query_state
+                .iter(world)
+                .map(|(entity, item)| (entity, key(item)))
+                .collect::<Vec<_>>();
+            if keyed != unsorted {
+                sorted = keyed.clone();
+                sorted.sort_by(|(entity_a, key_a), (entity_b, key_b)| {
+                    key_a.cmp(key_b).then(entity_a.cmp(entity_b))
+                });
+                unsorted = keyed;
+            }
+            sorted.clone()
+        }
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct WindowSize {
+    pub width: f32,
+    pub height: f32,
+    pub scale_factor: f64,
+}
+
+/// Returns the logical size and scale factor of the primary window, re-rendering the component
+/// only on ticks where they have changed. Returns zeros if there is no primary window.
+// TODO: Target the window of the root's camera once roots can be bound to a camera
+pub fn use_window(cx: &ScopeState) -> WindowSize {
+    use_watched_value(cx, |cx| {
+        let mut query_state =
+            QueryState::<&Window, With<PrimaryWindow>>::new(EcsContext::get_world(cx));
+        move |world: &World| {
+            query_state
+                .get_single(world)
+                .map(|window| WindowSize {
+                    width: window.width(),
+                    height: window.height(),
+                    scale_factor: window.scale_factor(),
+                })
+                .unwrap_or_default()
+        }
+    })
+}
+
+/// Returns the value read from the world by the closure `init` returns, re-running it every tick
+/// and re-rendering the component whenever the value changes.
+pub(crate) fn use_watched_value<T, R>(cx: &ScopeState, init: impl FnOnce(&ScopeState) -> R) -> T
+where
+    T: PartialEq + Clone + 'static,
+    R: FnMut(&World) -> T + 'static,
+{
+    let scope_id = cx.scope_id();
+    let (value, subscription_manager) = cx.use_hook(|| {
+        let mut read_value = init(cx);
+        let value = Rc::new(RefCell::new(read_value(EcsContext::get_world_ref(cx))));
+        let value_changed = {
+            let value = Rc::clone(&value);
+            move |world: &World| {
+                let new_value = read_value(world);
+                if *value.borrow() == new_value {
+                    return false;
+                }
+                *value.borrow_mut() = new_value;
+                true
+            }
+        };
+
+        let subscription_manager = &mut EcsContext::get_subscriptions(cx).watched_values;
+        subscription_manager
+            .entry(scope_id)
+            .or_default()
+            .push(Box::new(value_changed));
+        (
+            value,
+            Box::as_mut(subscription_manager)
+                as *mut HashMap<ScopeId, Vec<Box<dyn FnMut(&World) -> bool>>>,
+        )
+    });
+    let subscription_manager = *subscription_manager;
+    use_on_destroy(cx, move || {
+        unsafe { &mut *subscription_manager }.remove(&scope_id);
+    });
+
+    T::clone(&value.borrow())
+}
+
+/// Returns the children of `entity`, re-rendering the component only on ticks where they have
+/// changed. Returns an empty list if it has no children or doesn't exist.
+pub fn use_children(cx: &ScopeState, entity: Entity) -> Vec<Entity> {
+    let watched_entity = cx.use_hook(|| Rc::new(Cell::new(entity)));
+    let children = use_watched_value(cx, |_| {
+        let watched_entity = Rc::clone(watched_entity);
+        move |world: &World| children_of(watched_entity.get(), world)
+    });
+
+    if watched_entity.replace(entity) == entity {
+        children
+    } else {
+        children_of(entity, EcsContext::get_world_ref(cx))
+    }
+}
+
+fn children_of(entity: Entity, world: &World) -> Vec<Entity> {
+    world
+        .get::<Children>(entity)
+        .map(|children| children.to_vec())
+        .unwrap_or_default()
+}
+
+/// Returns the payloads of [`DioxusUiEvent`]s named `name` sent to this component's root since
+/// its last render, re-rendering the component whenever one is sent.
+pub fn use_custom_event<'a>(cx: &'a ScopeState, name: &str) -> Vec<&'a dyn Reflect> {
+    let scope_id = cx.scope_id();
+    let (event_reader, subscription_manager) = cx.use_hook(|| {
+        let subscription_manager = &mut EcsContext::get_subscriptions(cx).custom_events;
+        subscription_manager
+            .entry(name.to_owned())
+            .or_default()
+            .insert(scope_id);
+        (
+            EcsContext::get_world_ref(cx)
+                .resource::<Events<DioxusUiEvent>>()
+                .get_reader_current(),
+            Box::as_mut(subscription_manager) as *mut HashMap<String, HashSet<ScopeId>>,
+        )
+    });
+    let subscription_manager = *subscription_manager;
+    let subscribed_name = name.to_owned();
+    use_on_destroy(cx, move || {
+        let subscription_manager = unsafe { &mut *subscription_manager };
+        let event_subscriptions = subscription_manager.get_mut(&subscribed_name).unwrap();
+        event_subscriptions.remove(&scope_id);
+        if event_subscriptions.is_empty() {
+            subscription_manager.remove(&subscribed_name);
+        }
+    });
 
-    let event_reader = cx.use_hook(ManualEventReader::default);
-    let events = EcsContext::get_world(cx).resource::<Events<E>>();
-    event_reader.read(events)
+    let root_entity = EcsContext::get_root_entity(cx);
+    event_reader
+        .read(EcsContext::get_world_ref(cx).resource::<Events<DioxusUiEvent>>())
+        .filter(|event| event.root == root_entity && event.name == name)
+        .map(|event| &*event.payload)
+        .collect()
 }
 
 pub struct UseQuery<'a, Q: ReadOnlyWorldQuery, F: ReadOnlyWorldQuery> {
-    query_state: QueryState<Q, F>,
+    query_state: &'a QueryState<Q, F>,
     world_cell: UnsafeWorldCell<'a>,
 }
 
@@ -142,7 +437,7 @@ where
         unsafe {
             Query::new(
                 self.world_cell,
-                &self.query_state,
+                self.query_state,
                 self.world_cell.last_change_tick(),
                 self.world_cell.change_tick(),
                 true,
@@ -1,11 +1,28 @@
 macro_rules! node_attributes {
     () => {
+        pub const node_ref: AttributeDescription = ("node_ref", None, false);
+        pub const class: AttributeDescription = ("class", None, false);
+        pub const role: AttributeDescription = ("role", None, false);
+        pub const aria_label: AttributeDescription = ("aria_label", None, false);
+        pub const aria_live: AttributeDescription = ("aria_live", None, false);
+        pub const aria_hidden: AttributeDescription = ("aria_hidden", None, false);
+        pub const tab_index: AttributeDescription = ("tab_index", None, false);
+        pub const drag_data: AttributeDescription = ("drag_data", None, false);
+        pub const cursor: AttributeDescription = ("cursor", None, false);
+        pub const cursor_hotspot: AttributeDescription = ("cursor_hotspot", None, false);
+        pub const text_size: AttributeDescription = ("text_size", None, false);
+        pub const text_color: AttributeDescription = ("text_color", None, false);
         pub const animate: AttributeDescription = ("animate", None, false);
         pub const display: AttributeDescription = ("display", None, false);
         pub const position: AttributeDescription = ("position", None, false);
         pub const overflow: AttributeDescription = ("overflow", None, false);
         pub const overflow_x: AttributeDescription = ("overflow_x", None, false);
         pub const overflow_y: AttributeDescription = ("overflow_y", None, false);
+        pub const scrollbar: AttributeDescription = ("scrollbar", None, false);
+        pub const sticky: AttributeDescription = ("sticky", None, false);
+        pub const scrollbar_color: AttributeDescription = ("scrollbar_color", None, false);
+        pub const scrollbar_thumb_color: AttributeDescription =
+            ("scrollbar_thumb_color", None, false);
         pub const left: AttributeDescription = ("left", None, false);
         pub const right: AttributeDescription = ("right", None, false);
         pub const top: AttributeDescription = ("top", None, false);
@@ -40,8 +57,11 @@ macro_rules! node_attributes {
         pub const outline_width: AttributeDescription = ("outline_width", None, false);
         pub const outline_offset: AttributeDescription = ("outline_offset", None, false);
         pub const outline_color: AttributeDescription = ("outline_color", None, false);
+        /// Parsed but ignored, bevy_ui has no box shadows.
+        pub const box_shadow: AttributeDescription = ("box_shadow", None, false);
         pub const flex_direction: AttributeDescription = ("flex_direction", None, false);
         pub const flex_wrap: AttributeDescription = ("flex_wrap", None, false);
+        pub const flex: AttributeDescription = ("flex", None, false);
         pub const flex_grow: AttributeDescription = ("flex_grow", None, false);
         pub const flex_shrink: AttributeDescription = ("flex_shrink", None, false);
         pub const flex_basis: AttributeDescription = ("flex_basis", None, false);
@@ -56,6 +76,10 @@ macro_rules! node_attributes {
         pub const grid_row: AttributeDescription = ("grid_row", None, false);
         pub const grid_column: AttributeDescription = ("grid_column", None, false);
         pub const background_color: AttributeDescription = ("background_color", None, false);
+        pub const hover_background_color: AttributeDescription =
+            ("hover_background_color", None, false);
+        pub const active_background_color: AttributeDescription =
+            ("active_background_color", None, false);
         pub const translation: AttributeDescription = ("translation", None, false);
         pub const translation_x: AttributeDescription = ("translation", None, false);
         pub const translation_y: AttributeDescription = ("translation", None, false);
@@ -90,8 +114,12 @@ pub mod dioxus_elements {
         pub const text_direction: AttributeDescription = ("text_direction", None, false);
         pub const text_multiline_alignment: AttributeDescription =
             ("text_multiline_alignment", None, false);
-        pub const text_size: AttributeDescription = ("text_size", None, false);
-        pub const text_color: AttributeDescription = ("text_color", None, false);
+        pub const font: AttributeDescription = ("font", None, false);
+        pub const text_shadow: AttributeDescription = ("text_shadow", None, false);
+        pub const text_transform: AttributeDescription = ("text_transform", None, false);
+        /// Parsed but ignored, bevy_text has no letter spacing.
+        pub const letter_spacing: AttributeDescription = ("letter_spacing", None, false);
+        pub const white_space: AttributeDescription = ("white_space", None, false);
         node_attributes!();
     }
 
@@ -100,6 +128,10 @@ pub mod dioxus_elements {
         pub const TAG_NAME: &'static str = "image";
         pub const NAME_SPACE: Option<&'static str> = NAME_SPACE;
         pub const image_asset_path: AttributeDescription = ("image_asset_path", None, false);
+        pub const object_fit: AttributeDescription = ("object_fit", None, false);
+        /// Only `no_repeat` is supported, stretching the image to the node; `repeat` is ignored
+        /// with a warning as bevy_ui can't tile images.
+        pub const background_repeat: AttributeDescription = ("background_repeat", None, false);
         node_attributes!();
     }
 }
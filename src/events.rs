@@ -1,38 +1,123 @@
+use crate::{pointer_capture::PointerCapture, scroll::wheel_delta, DioxusUiRoot};
 use bevy::{
     ecs::{
         component::Component,
         entity::Entity,
-        event::{Event, EventWriter, Events, ManualEventReader},
-        system::{Local, Query, Resource},
+        event::{Event, EventReader, EventWriter, Events, ManualEventReader},
+        query::{Changed, Or, With},
+        system::{Local, Query, Res, Resource},
         world::World,
     },
     hierarchy::Parent,
+    input::mouse::MouseWheel,
+    math::Vec2,
     prelude::EntityWorldMut,
-    ui::RelativeCursorPosition,
-    utils::EntityHashSet,
+    reflect::Reflect,
+    transform::components::GlobalTransform,
+    ui::{CalculatedClip, Node, UiStack},
+    utils::{EntityHashMap, HashMap},
+};
+use bevy_mod_picking::{
+    events::{Click, Down, Drag, DragEnd, DragStart, Drop as DragDrop, Out, Over, Pointer, Up},
+    focus::HoverMap,
+    pointer::{PointerButton, PointerId, PointerLocation},
 };
-use bevy_mod_picking::events::{Click, Down, Out, Over, Pointer, Up};
 use dioxus::core::ScopeState;
-use std::{any::Any, mem, rc::Rc};
+use std::{any::Any, cmp::Reverse, mem, rc::Rc};
 
 // TODO: Other events
 pub mod events {
+    use super::{
+        PointerDrag, PointerDragEnd, PointerDrop, PointerHover, PointerPress, PointerWheel,
+    };
+    use bevy::{ecs::entity::Entity, math::Vec2};
     use bevy_mod_picking::pointer::PointerButton;
 
     super::impl_event! [
         ();
         onmouse_over
         onmouse_out
+    ];
+
+    super::impl_event! [
+        PointerHover;
+        /// Touches have no hover state, they enter the node when pressed and exit when lifted.
         onmouse_enter
         onmouse_exit
     ];
 
     super::impl_event! [
         PointerButton;
+        /// Touch taps, including tap-and-hold, click with `PointerButton::Primary` on release.
         onclick
         onclick_down
         onclick_up
     ];
+
+    super::impl_event! [
+        PointerButton;
+        /// Like `onclick`, but only for [`PointerButton::Secondary`].
+        onclick_secondary
+        onclick_secondary_down
+        onclick_secondary_up
+        /// Like `onclick`, but only for [`PointerButton::Middle`].
+        onclick_middle
+        onclick_middle_down
+        onclick_middle_up
+    ];
+
+    super::impl_event! [
+        PointerPress;
+        /// Fires for every press of any button, with where and by which pointer it happened, e.g.
+        /// to start panning on a middle press.
+        onmouse_down
+        /// Fires for every release of any button over the node, even if it was pressed elsewhere.
+        onmouse_up
+    ];
+
+    super::impl_event! [
+        PointerPress;
+        /// Fires when a pressed pointer first moves after pressing down on the node, and captures
+        /// the pointer for the node, see [`PointerCapture`](crate::prelude::PointerCapture).
+        ondragstart
+    ];
+
+    super::impl_event! [
+        PointerDrag;
+        ondrag
+    ];
+
+    super::impl_event! [
+        PointerDragEnd;
+        /// Fires when the button that started a drag is released, wherever the pointer is.
+        ondragend
+    ];
+
+    super::impl_event! [
+        PointerDrop;
+        ondrop
+    ];
+
+    super::impl_event! [
+        PointerWheel;
+        /// Fires when the mouse wheel turns over the node. Scrolling nodes around it don't scroll
+        /// while it has a listener.
+        onwheel
+    ];
+
+    super::impl_event! [
+        Vec2;
+        onresize
+    ];
+
+    // TODO: `onunmount`. Dioxus drops an element's listeners as it's removed, before the renderer
+    // could call them, so use `use_on_destroy` in the component owning the element instead.
+    super::impl_event! [
+        Entity;
+        /// Receives the element's Bevy entity on the tick after it's spawned, or after the listener
+        /// is added to an existing element.
+        onmount
+    ];
 }
 
 #[derive(Resource, Default)]
@@ -40,10 +125,19 @@ pub struct EventReaders {
     click: ManualEventReader<Pointer<Click>>,
     click_down: ManualEventReader<Pointer<Down>>,
     click_up: ManualEventReader<Pointer<Up>>,
+    drag_start: ManualEventReader<Pointer<DragStart>>,
+    drag: ManualEventReader<Pointer<Drag>>,
+    drag_end: ManualEventReader<Pointer<DragEnd>>,
+    drop: ManualEventReader<Pointer<DragDrop>>,
     mouse_over: ManualEventReader<Pointer<Over>>,
     mouse_out: ManualEventReader<Pointer<Out>>,
     mouse_enter: ManualEventReader<MouseEnter>,
     mouse_exit: ManualEventReader<MouseExit>,
+    wheel: ManualEventReader<Wheel>,
+    resize: ManualEventReader<Resize>,
+    mount: ManualEventReader<Mount>,
+    custom: ManualEventReader<DioxusUiEvent>,
+    rerender: ManualEventReader<RequestDioxusRerender>,
 }
 
 impl EventReaders {
@@ -53,21 +147,128 @@ impl EventReaders {
         click: &Events<Pointer<Click>>,
         click_down: &Events<Pointer<Down>>,
         click_up: &Events<Pointer<Up>>,
+        drag_start: &Events<Pointer<DragStart>>,
+        drag: &Events<Pointer<Drag>>,
+        drag_end: &Events<Pointer<DragEnd>>,
+        drop: &Events<Pointer<DragDrop>>,
         mouse_over: &Events<Pointer<Over>>,
         mouse_out: &Events<Pointer<Out>>,
         mouse_enter: &Events<MouseEnter>,
         mouse_exit: &Events<MouseExit>,
+        wheel: &Events<Wheel>,
+        resize: &Events<Resize>,
+        mount: &Events<Mount>,
+        ui_stack: &UiStack,
+        pointer_capture: &PointerCapture,
+        world: &World,
     ) -> Vec<(Entity, &'static str, Rc<dyn Any>, bool)> {
+        // Picking can hit several overlapping nodes at once (e.g. with non-blocking `Pickable`s),
+        // and doesn't report them in any particular order. Deliver those front-to-back according
+        // to the UI stack, which accounts for `ZIndex`, so modals and dropdowns handle them first.
+        let stack_indices = ui_stack
+            .uinodes
+            .iter()
+            .enumerate()
+            .map(|(index, entity)| (*entity, index))
+            .collect::<EntityHashMap<_, _>>();
+        let front_to_back = |events: &mut [(Entity, &'static str, Rc<dyn Any>, bool)]| {
+            events.sort_by_key(|(target, ..)| Reverse(stack_indices.get(target).copied()));
+        };
+
         let mut events: Vec<(Entity, &'static str, Rc<dyn Any>, bool)> = Vec::new();
         for event in self.click.read(click) {
             events.push((event.target, "click", Rc::new(event.button), true));
+            if let Some(name) = button_event_name(event.button, "click_secondary", "click_middle") {
+                events.push((event.target, name, Rc::new(event.button), true));
+            }
         }
+        front_to_back(&mut events);
+        let start = events.len();
         for event in self.click_down.read(click_down) {
             events.push((event.target, "click_down", Rc::new(event.button), true));
+            let press = PointerPress {
+                pointer_id: event.pointer_id,
+                button: event.button,
+                position: event.pointer_location.position,
+            };
+            events.push((event.target, "mouse_down", Rc::new(press), true));
+            if let Some(name) =
+                button_event_name(event.button, "click_secondary_down", "click_middle_down")
+            {
+                events.push((event.target, name, Rc::new(event.button), true));
+            }
         }
+        front_to_back(&mut events[start..]);
+        let start = events.len();
         for event in self.click_up.read(click_up) {
             events.push((event.target, "click_up", Rc::new(event.button), true));
+            let press = PointerPress {
+                pointer_id: event.pointer_id,
+                button: event.button,
+                position: event.pointer_location.position,
+            };
+            events.push((event.target, "mouse_up", Rc::new(press), true));
+            if let Some(name) =
+                button_event_name(event.button, "click_secondary_up", "click_middle_up")
+            {
+                events.push((event.target, name, Rc::new(event.button), true));
+            }
+        }
+        front_to_back(&mut events[start..]);
+        // Captured pointers drag the capturing node, unless it was despawned since
+        let captured_target = |pointer_id: PointerId, target: Entity| {
+            pointer_capture
+                .get(pointer_id)
+                .filter(|entity| world.get_entity(*entity).is_some())
+                .unwrap_or(target)
+        };
+        let start = events.len();
+        for event in self.drag_start.read(drag_start) {
+            let press = PointerPress {
+                pointer_id: event.pointer_id,
+                button: event.button,
+                position: event.pointer_location.position,
+            };
+            events.push((event.target, "dragstart", Rc::new(press), true));
+        }
+        front_to_back(&mut events[start..]);
+        let start = events.len();
+        for event in self.drag.read(drag) {
+            let drag = PointerDrag {
+                pointer_id: event.pointer_id,
+                button: event.button,
+                delta: event.delta,
+            };
+            let target = captured_target(event.pointer_id, event.target);
+            events.push((target, "drag", Rc::new(drag), true));
         }
+        front_to_back(&mut events[start..]);
+        let start = events.len();
+        for event in self.drag_end.read(drag_end) {
+            let drag_end = PointerDragEnd {
+                pointer_id: event.pointer_id,
+                button: event.button,
+                distance: event.distance,
+            };
+            let target = captured_target(event.pointer_id, event.target);
+            events.push((target, "dragend", Rc::new(drag_end), true));
+        }
+        front_to_back(&mut events[start..]);
+        let start = events.len();
+        for event in self.drop.read(drop) {
+            let drop = PointerDrop {
+                pointer_id: event.pointer_id,
+                button: event.button,
+                data: drag_data(event.dropped, world),
+            };
+            events.push((event.target, "drop", Rc::new(drop), true));
+        }
+        front_to_back(&mut events[start..]);
+        let start = events.len();
+        for event in self.wheel.read(wheel) {
+            events.push((event.target, "wheel", Rc::new(event.wheel), true));
+        }
+        front_to_back(&mut events[start..]);
         for event in self.mouse_over.read(mouse_over) {
             events.push((event.target, "mouse_over", Rc::new(()), false));
         }
@@ -75,28 +276,79 @@ impl EventReaders {
             events.push((event.target, "mouse_out", Rc::new(()), false));
         }
         for event in self.mouse_enter.read(mouse_enter) {
-            events.push((event.target, "mouse_enter", Rc::new(()), false));
+            events.push((event.target, "mouse_enter", Rc::new(event.hover), false));
         }
         for event in self.mouse_exit.read(mouse_exit) {
-            events.push((event.target, "mouse_exit", Rc::new(()), false));
+            events.push((event.target, "mouse_exit", Rc::new(event.hover), false));
+        }
+        for event in self.resize.read(resize) {
+            events.push((event.target, "resize", Rc::new(event.size), false));
+        }
+        for event in self.mount.read(mount) {
+            events.push((event.target, "mount", Rc::new(event.target), false));
         }
         events
     }
 }
 
+/// Picks the name of the button-specific variant of a click event, if the button has one.
+fn button_event_name(
+    button: PointerButton,
+    secondary: &'static str,
+    middle: &'static str,
+) -> Option<&'static str> {
+    match button {
+        PointerButton::Primary => None,
+        PointerButton::Secondary => Some(secondary),
+        PointerButton::Middle => Some(middle),
+    }
+}
+
+impl EventReaders {
+    /// Returns the root entity and name of each new [`DioxusUiEvent`].
+    pub fn read_custom_events(&mut self, custom: &Events<DioxusUiEvent>) -> Vec<(Entity, String)> {
+        self.custom
+            .read(custom)
+            .map(|event| (event.root, event.name.clone()))
+            .collect()
+    }
+
+    /// Returns the root entity of each new [`RequestDioxusRerender`].
+    pub fn read_rerender_requests(
+        &mut self,
+        rerender: &Events<RequestDioxusRerender>,
+    ) -> Vec<Entity> {
+        self.rerender.read(rerender).map(|event| event.0).collect()
+    }
+}
+
 pub fn insert_event_listener(name: &str, mut entity: EntityWorldMut<'_>) {
     match name {
         "click" => entity.insert(HasClickEventListener),
         "click_down" => entity.insert(HasClickDownEventListener),
         "click_up" => entity.insert(HasClickUpEventListener),
+        "click_secondary" => entity.insert(HasClickSecondaryEventListener),
+        "click_secondary_down" => entity.insert(HasClickSecondaryDownEventListener),
+        "click_secondary_up" => entity.insert(HasClickSecondaryUpEventListener),
+        "click_middle" => entity.insert(HasClickMiddleEventListener),
+        "click_middle_down" => entity.insert(HasClickMiddleDownEventListener),
+        "click_middle_up" => entity.insert(HasClickMiddleUpEventListener),
+        "mouse_down" => entity.insert(HasMouseDownEventListener),
+        "mouse_up" => entity.insert(HasMouseUpEventListener),
+        "dragstart" => entity.insert(HasDragStartEventListener),
+        "drag" => entity.insert(HasDragEventListener),
+        "dragend" => entity.insert(HasDragEndEventListener),
+        "drop" => entity.insert(HasDropEventListener),
         "mouse_over" => &mut entity,
         "mouse_out" => &mut entity,
-        "mouse_enter" => entity.insert((
-            HasMouseEnterEventListener,
-            RelativeCursorPosition::default(),
-        )),
-        "mouse_exit" => {
-            entity.insert((HasMouseExitEventListener, RelativeCursorPosition::default()))
+        "mouse_enter" => entity.insert(HasMouseEnterEventListener),
+        "mouse_exit" => entity.insert(HasMouseExitEventListener),
+        "wheel" => entity.insert(HasWheelEventListener),
+        "resize" => entity.insert(HasResizeEventListener),
+        "mount" => {
+            let target = entity.id();
+            entity.world_scope(|world| world.send_event(Mount { target }));
+            &mut entity
         }
         _ => panic!("Encountered unsupported bevy_dioxus event `{name}`."),
     };
@@ -107,22 +359,25 @@ pub fn remove_event_listener(name: &str, mut entity: EntityWorldMut<'_>) {
         "click" => entity.remove::<HasClickEventListener>(),
         "click_down" => entity.remove::<HasClickDownEventListener>(),
         "click_up" => entity.remove::<HasClickUpEventListener>(),
+        "click_secondary" => entity.remove::<HasClickSecondaryEventListener>(),
+        "click_secondary_down" => entity.remove::<HasClickSecondaryDownEventListener>(),
+        "click_secondary_up" => entity.remove::<HasClickSecondaryUpEventListener>(),
+        "click_middle" => entity.remove::<HasClickMiddleEventListener>(),
+        "click_middle_down" => entity.remove::<HasClickMiddleDownEventListener>(),
+        "click_middle_up" => entity.remove::<HasClickMiddleUpEventListener>(),
+        "mouse_down" => entity.remove::<HasMouseDownEventListener>(),
+        "mouse_up" => entity.remove::<HasMouseUpEventListener>(),
+        "dragstart" => entity.remove::<HasDragStartEventListener>(),
+        "drag" => entity.remove::<HasDragEventListener>(),
+        "dragend" => entity.remove::<HasDragEndEventListener>(),
+        "drop" => entity.remove::<HasDropEventListener>(),
         "mouse_over" => &mut entity,
         "mouse_out" => &mut entity,
-        "mouse_enter" => {
-            entity.remove::<HasMouseEnterEventListener>();
-            if !entity.contains::<HasMouseExitEventListener>() {
-                entity.remove::<RelativeCursorPosition>();
-            }
-            &mut entity
-        }
-        "mouse_exit" => {
-            entity.remove::<HasMouseExitEventListener>();
-            if !entity.contains::<HasMouseEnterEventListener>() {
-                entity.remove::<RelativeCursorPosition>();
-            }
-            &mut entity
-        }
+        "mouse_enter" => entity.remove::<HasMouseEnterEventListener>(),
+        "mouse_exit" => entity.remove::<HasMouseExitEventListener>(),
+        "wheel" => entity.remove::<HasWheelEventListener>(),
+        "resize" => entity.remove::<HasResizeEventListener>(),
+        "mount" => &mut entity,
         _ => unreachable!(),
     };
 }
@@ -136,12 +391,54 @@ pub struct HasClickDownEventListener;
 #[derive(Component)]
 pub struct HasClickUpEventListener;
 
+#[derive(Component)]
+pub struct HasClickSecondaryEventListener;
+
+#[derive(Component)]
+pub struct HasClickSecondaryDownEventListener;
+
+#[derive(Component)]
+pub struct HasClickSecondaryUpEventListener;
+
+#[derive(Component)]
+pub struct HasClickMiddleEventListener;
+
+#[derive(Component)]
+pub struct HasClickMiddleDownEventListener;
+
+#[derive(Component)]
+pub struct HasClickMiddleUpEventListener;
+
+#[derive(Component)]
+pub struct HasMouseDownEventListener;
+
+#[derive(Component)]
+pub struct HasMouseUpEventListener;
+
+#[derive(Component)]
+pub struct HasDragStartEventListener;
+
+#[derive(Component)]
+pub struct HasDragEventListener;
+
+#[derive(Component)]
+pub struct HasDragEndEventListener;
+
+#[derive(Component)]
+pub struct HasDropEventListener;
+
 #[derive(Component)]
 pub struct HasMouseEnterEventListener;
 
 #[derive(Component)]
 pub struct HasMouseExitEventListener;
 
+#[derive(Component)]
+pub struct HasWheelEventListener;
+
+#[derive(Component)]
+pub struct HasResizeEventListener;
+
 // ----------------------------------------------------------------------------
 
 pub fn bubble_event(event_name: &str, target_entity: &mut Entity, world: &World) {
@@ -149,12 +446,39 @@ pub fn bubble_event(event_name: &str, target_entity: &mut Entity, world: &World)
         "click" => bubble_event_helper::<HasClickEventListener>(target_entity, world),
         "click_down" => bubble_event_helper::<HasClickDownEventListener>(target_entity, world),
         "click_up" => bubble_event_helper::<HasClickUpEventListener>(target_entity, world),
+        "click_secondary" => {
+            bubble_event_helper::<HasClickSecondaryEventListener>(target_entity, world)
+        }
+        "click_secondary_down" => {
+            bubble_event_helper::<HasClickSecondaryDownEventListener>(target_entity, world)
+        }
+        "click_secondary_up" => {
+            bubble_event_helper::<HasClickSecondaryUpEventListener>(target_entity, world)
+        }
+        "click_middle" => bubble_event_helper::<HasClickMiddleEventListener>(target_entity, world),
+        "click_middle_down" => {
+            bubble_event_helper::<HasClickMiddleDownEventListener>(target_entity, world)
+        }
+        "click_middle_up" => {
+            bubble_event_helper::<HasClickMiddleUpEventListener>(target_entity, world)
+        }
+        "mouse_down" => bubble_event_helper::<HasMouseDownEventListener>(target_entity, world),
+        "mouse_up" => bubble_event_helper::<HasMouseUpEventListener>(target_entity, world),
+        "dragstart" => bubble_event_helper::<HasDragStartEventListener>(target_entity, world),
+        "drag" => bubble_event_helper::<HasDragEventListener>(target_entity, world),
+        "dragend" => bubble_event_helper::<HasDragEndEventListener>(target_entity, world),
+        "drop" => bubble_event_helper::<HasDropEventListener>(target_entity, world),
+        "wheel" => bubble_event_helper::<HasWheelEventListener>(target_entity, world),
         _ => unreachable!(),
     };
 }
 
 fn bubble_event_helper<T: Component>(target_entity: &mut Entity, world: &World) {
     while !world.entity(*target_entity).contains::<T>() {
+        // Don't leak events out of a `NestedRoot` into the root it's mounted in
+        if world.entity(*target_entity).contains::<DioxusUiRoot>() {
+            return;
+        }
         *target_entity = match world.entity(*target_entity).get::<Parent>() {
             Some(parent) => parent.get(),
             None => return,
@@ -164,45 +488,226 @@ fn bubble_event_helper<T: Component>(target_entity: &mut Entity, world: &World)
 
 // ----------------------------------------------------------------------------
 
+/// Sent with `onmouse_down`, `onmouse_up` and `ondragstart`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PointerPress {
+    pub pointer_id: PointerId,
+    pub button: PointerButton,
+    /// The pointer position in logical pixels.
+    pub position: Vec2,
+}
+
+/// Sent with `ondrag` for every frame a pressed pointer moves after pressing down on the node.
+///
+/// Touches drag with [`PointerButton::Primary`], and each finger of a multi-touch gesture is its
+/// own pointer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PointerDrag {
+    pub pointer_id: PointerId,
+    pub button: PointerButton,
+    /// The distance moved since the last drag event, in logical pixels.
+    pub delta: Vec2,
+}
+
+/// Sent with `ondragend` when a drag is released.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PointerDragEnd {
+    pub pointer_id: PointerId,
+    pub button: PointerButton,
+    /// The distance from where the drag started to where it was released, in logical pixels.
+    pub distance: Vec2,
+}
+
+/// Sent with `ondrop` when a drag started on another element ends over this one.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PointerDrop {
+    pub pointer_id: PointerId,
+    pub button: PointerButton,
+    /// The `drag_data` attribute of the dragged element or its nearest ancestor that has one,
+    /// e.g. an entity id to reparent.
+    pub data: Option<String>,
+}
+
+/// The value of a `drag_data` attribute.
+#[derive(Component, Clone)]
+pub(crate) struct DragData(pub String);
+
+fn drag_data(mut entity: Entity, world: &World) -> Option<String> {
+    loop {
+        let entity_ref = world.get_entity(entity)?;
+        if let Some(drag_data) = entity_ref.get::<DragData>() {
+            return Some(drag_data.0.clone());
+        }
+        if entity_ref.contains::<DioxusUiRoot>() {
+            return None;
+        }
+        entity = entity_ref.get::<Parent>()?.get();
+    }
+}
+
+/// Sent with `onwheel`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PointerWheel {
+    /// How far the wheel scrolls, in logical pixels. Positive `y` scrolls up, positive `x` right.
+    pub delta: Vec2,
+}
+
+/// Sent with `onmouse_enter` and `onmouse_exit`, one per pointer crossing the node's edge.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PointerHover {
+    pub pointer_id: PointerId,
+    /// The pointer position in logical pixels, or its last known position when it was removed
+    /// (e.g. a lifted touch).
+    pub position: Vec2,
+}
+
+#[allow(clippy::type_complexity)]
 pub fn generate_mouse_enter_leave_events(
-    entities: Query<(Entity, &RelativeCursorPosition)>,
-    mut previous_over: Local<EntityHashSet<Entity>>,
-    mut over: Local<EntityHashSet<Entity>>,
+    nodes: Query<
+        (Entity, &Node, &GlobalTransform, Option<&CalculatedClip>),
+        Or<(
+            With<HasMouseEnterEventListener>,
+            With<HasMouseExitEventListener>,
+        )>,
+    >,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    mut previous_over: Local<HashMap<(PointerId, Entity), Vec2>>,
+    mut over: Local<HashMap<(PointerId, Entity), Vec2>>,
     mut enter: EventWriter<MouseEnter>,
     mut leave: EventWriter<MouseExit>,
 ) {
-    mem::swap::<EntityHashSet<Entity>>(&mut previous_over, &mut over);
+    mem::swap::<HashMap<(PointerId, Entity), Vec2>>(&mut previous_over, &mut over);
 
+    // Unlike picking's hover, a node stays entered while the pointer is over one of its children
     over.clear();
-    for (entity, relative_cursor_position) in &entities {
-        if relative_cursor_position.mouse_over() {
-            over.insert(entity);
+    for (pointer_id, pointer_location) in &pointers {
+        let Some(location) = &pointer_location.location else {
+            continue;
+        };
+        for (entity, node, global_transform, clip) in &nodes {
+            let mut rect = node.logical_rect(global_transform);
+            if let Some(clip) = clip {
+                rect = rect.intersect(clip.clip);
+            }
+            if rect.contains(location.position) {
+                over.insert((*pointer_id, entity), location.position);
+            }
         }
     }
 
     enter.send_batch(
         over.iter()
-            .copied()
-            .filter(|entity| !previous_over.contains(entity))
-            .map(|target| MouseEnter { target }),
+            .filter(|(key, _)| !previous_over.contains_key(key))
+            .map(|(&(pointer_id, target), &position)| MouseEnter {
+                target,
+                hover: PointerHover {
+                    pointer_id,
+                    position,
+                },
+            }),
     );
 
+    let current_positions = pointers
+        .iter()
+        .filter_map(|(pointer_id, pointer_location)| {
+            Some((*pointer_id, pointer_location.location.as_ref()?.position))
+        })
+        .collect::<HashMap<_, _>>();
     leave.send_batch(
         previous_over
             .iter()
-            .copied()
-            .filter(|entity| !over.contains(entity))
-            .map(|target| MouseExit { target }),
+            .filter(|(key, _)| !over.contains_key(key))
+            .map(|(&(pointer_id, target), &last_position)| MouseExit {
+                target,
+                hover: PointerHover {
+                    pointer_id,
+                    position: current_positions
+                        .get(&pointer_id)
+                        .copied()
+                        .unwrap_or(last_position),
+                },
+            }),
     );
 }
 
 #[derive(Event)]
 pub struct MouseEnter {
-    target: Entity,
+    pub(crate) target: Entity,
+    pub(crate) hover: PointerHover,
 }
 
 #[derive(Event)]
 pub struct MouseExit {
+    pub(crate) target: Entity,
+    pub(crate) hover: PointerHover,
+}
+
+/// Sent by systems to notify components of the `DioxusUiRoot` on `root` that subscribed to
+/// `name` via `use_custom_event`.
+#[derive(Event)]
+pub struct DioxusUiEvent {
+    pub root: Entity,
+    pub name: String,
+    pub payload: Box<dyn Reflect>,
+}
+
+/// Re-renders the root component of the `DioxusUiRoot` on this entity next tick.
+///
+/// A last resort for integrations that change state the hooks don't track. Prefer `use_resource`,
+/// `use_query` and the other hooks, which re-render exactly the components that read the changed
+/// data. Child components whose props are unchanged are not re-rendered.
+#[derive(Event)]
+pub struct RequestDioxusRerender(pub Entity);
+
+// ----------------------------------------------------------------------------
+
+pub fn generate_resize_events(
+    nodes: Query<(Entity, &Node), (With<HasResizeEventListener>, Changed<Node>)>,
+    mut previous_sizes: Local<EntityHashMap<Entity, Vec2>>,
+    mut resize: EventWriter<Resize>,
+) {
+    for (entity, node) in &nodes {
+        let size = node.size();
+        if previous_sizes.insert(entity, size) != Some(size) {
+            resize.send(Resize {
+                target: entity,
+                size,
+            });
+        }
+    }
+}
+
+pub fn generate_wheel_events(
+    mut mouse_wheel: EventReader<MouseWheel>,
+    hover_map: Res<HoverMap>,
+    mut wheel: EventWriter<Wheel>,
+) {
+    for event in mouse_wheel.read() {
+        let Some(hovered) = hover_map.get(&PointerId::Mouse) else {
+            continue;
+        };
+        let delta = wheel_delta(event);
+        wheel.send_batch(hovered.keys().map(|target| Wheel {
+            target: *target,
+            wheel: PointerWheel { delta },
+        }));
+    }
+}
+
+#[derive(Event)]
+pub struct Wheel {
+    target: Entity,
+    wheel: PointerWheel,
+}
+
+#[derive(Event)]
+pub struct Resize {
+    target: Entity,
+    size: Vec2,
+}
+
+#[derive(Event)]
+pub struct Mount {
     target: Entity,
 }
 
@@ -35,8 +35,11 @@ impl HotReloadingContext for HotReloadContext {
                 "text" => Some(("text", None)),
                 "text_direction" => Some(("text_direction", None)),
                 "text_multiline_alignment" => Some(("text_multiline_alignment", None)),
-                "text_size" => Some(("text_size", None)),
-                "text_color" => Some(("text_color", None)),
+                "font" => Some(("font", None)),
+                "text_shadow" => Some(("text_shadow", None)),
+                "text_transform" => Some(("text_transform", None)),
+                "letter_spacing" => Some(("letter_spacing", None)),
+                "white_space" => Some(("white_space", None)),
                 _ => None,
             };
             if let Some(attribute) = attribute {
@@ -46,6 +49,8 @@ impl HotReloadingContext for HotReloadContext {
         if element_name_rust == dioxus_elements::image::TAG_NAME {
             let attribute = match attribute_name_rust {
                 "image_asset_path" => Some(("image_asset_path", None)),
+                "object_fit" => Some(("object_fit", None)),
+                "background_repeat" => Some(("background_repeat", None)),
                 _ => None,
             };
             if let Some(attribute) = attribute {
@@ -55,12 +60,28 @@ impl HotReloadingContext for HotReloadContext {
         if let dioxus_elements::node::TAG_NAME | dioxus_elements::text::TAG_NAME = element_name_rust
         {
             match attribute_name_rust {
+                "node_ref" => Some(("node_ref", None)),
+                "class" => Some(("class", None)),
+                "role" => Some(("role", None)),
+                "aria_label" => Some(("aria_label", None)),
+                "aria_live" => Some(("aria_live", None)),
+                "aria_hidden" => Some(("aria_hidden", None)),
+                "tab_index" => Some(("tab_index", None)),
+                "drag_data" => Some(("drag_data", None)),
+                "cursor" => Some(("cursor", None)),
+                "cursor_hotspot" => Some(("cursor_hotspot", None)),
+                "text_size" => Some(("text_size", None)),
+                "text_color" => Some(("text_color", None)),
                 "animate" => Some(("animate", None)),
                 "display" => Some(("display", None)),
                 "position" => Some(("position", None)),
                 "overflow" => Some(("overflow", None)),
                 "overflow_x" => Some(("overflow_x", None)),
                 "overflow_y" => Some(("overflow_y", None)),
+                "scrollbar" => Some(("scrollbar", None)),
+                "sticky" => Some(("sticky", None)),
+                "scrollbar_color" => Some(("scrollbar_color", None)),
+                "scrollbar_thumb_color" => Some(("scrollbar_thumb_color", None)),
                 "left" => Some(("left", None)),
                 "right" => Some(("right", None)),
                 "top" => Some(("top", None)),
@@ -95,8 +116,10 @@ impl HotReloadingContext for HotReloadContext {
                 "outline_width" => Some(("outline_width", None)),
                 "outline_offset" => Some(("outline_offset", None)),
                 "outline_color" => Some(("outline_color", None)),
+                "box_shadow" => Some(("box_shadow", None)),
                 "flex_direction" => Some(("flex_direction", None)),
                 "flex_wrap" => Some(("flex_wrap", None)),
+                "flex" => Some(("flex", None)),
                 "flex_grow" => Some(("flex_grow", None)),
                 "flex_shrink" => Some(("flex_shrink", None)),
                 "flex_basis" => Some(("flex_basis", None)),
@@ -110,6 +133,8 @@ impl HotReloadingContext for HotReloadContext {
                 "grid_row" => Some(("grid_row", None)),
                 "grid_column" => Some(("grid_column", None)),
                 "background_color" => Some(("background_color", None)),
+                "hover_background_color" => Some(("hover_background_color", None)),
+                "active_background_color" => Some(("active_background_color", None)),
                 "translation" => Some(("translation", None)),
                 "translation_x" => Some(("translation_x", None)),
                 "translation_y" => Some(("translation_y", None)),
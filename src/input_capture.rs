@@ -0,0 +1,130 @@
+use crate::{events::HasClickEventListener, DioxusUiRoot, DioxusUiSettings};
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{Has, With},
+        system::{Query, Res, ResMut, Resource},
+    },
+    hierarchy::Parent,
+    input::{mouse::MouseButton, Input},
+    text::Text,
+    ui::{BackgroundColor, UiImage},
+};
+use bevy_mod_picking::focus::HoverMap;
+
+/// Which part of a `DioxusUiRoot` counts as being over the UI for [`PointerOverUi`].
+///
+/// Insert this next to `DioxusUiRoot`. Without it, roots behave as `Nodes`.
+#[derive(Component, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DioxusUiInputCapture {
+    /// Only visible elements (with a background color, text, or image) and clickable elements,
+    /// so that transparent layout containers don't hide the world behind them.
+    #[default]
+    Nodes,
+    /// The whole screen, for as long as the root exists.
+    Global,
+}
+
+/// Whether any pointer is over dioxus UI. Check this in game systems to skip world clicks that
+/// were meant for the UI.
+#[derive(Resource, Default)]
+pub struct PointerOverUi(pub bool);
+
+pub fn update_pointer_over_ui(
+    hover_map: Res<HoverMap>,
+    roots: Query<Option<&DioxusUiInputCapture>, With<DioxusUiRoot>>,
+    elements: Query<(
+        Option<&BackgroundColor>,
+        Has<Text>,
+        Has<UiImage>,
+        Has<HasClickEventListener>,
+        Option<&Parent>,
+    )>,
+    mut pointer_over_ui: ResMut<PointerOverUi>,
+) {
+    let over_ui = roots
+        .iter()
+        .any(|capture| capture == Some(&DioxusUiInputCapture::Global))
+        || hover_map
+            .values()
+            .flat_map(|hovered_entities| hovered_entities.keys())
+            .any(|entity| is_visible_ui_element(*entity, &roots, &elements));
+
+    if pointer_over_ui.0 != over_ui {
+        pointer_over_ui.0 = over_ui;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn is_visible_ui_element(
+    entity: Entity,
+    roots: &Query<Option<&DioxusUiInputCapture>, With<DioxusUiRoot>>,
+    elements: &Query<(
+        Option<&BackgroundColor>,
+        Has<Text>,
+        Has<UiImage>,
+        Has<HasClickEventListener>,
+        Option<&Parent>,
+    )>,
+) -> bool {
+    let Ok((background_color, has_text, has_image, has_click_listener, _)) = elements.get(entity)
+    else {
+        return false;
+    };
+    let is_visible = background_color.is_some_and(|background_color| background_color.0.a() > 0.0)
+        || has_text
+        || has_image
+        || has_click_listener;
+
+    if !is_visible {
+        return false;
+    }
+
+    // Only count the entity if it belongs to a DioxusUiRoot
+    let mut ancestor = Some(entity);
+    while let Some(current) = ancestor {
+        if roots.contains(current) {
+            return true;
+        }
+        ancestor = elements
+            .get(current)
+            .ok()
+            .and_then(|(.., parent)| parent.map(Parent::get));
+    }
+    false
+}
+
+/// Whether dioxus UI consumed this frame's mouse button presses, with
+/// `DioxusUiSettings::consume_input` enabled.
+///
+/// Consumed presses are also cleared from `Input<MouseButton>`, so game systems running after
+/// `PreUpdate` don't see them as `just_pressed`. Releases are left alone, so drags started in the
+/// world still end over the UI.
+// TODO: Consume touches too
+#[derive(Resource, Default)]
+pub struct UiInputConsumed(pub bool);
+
+pub fn consume_ui_input(
+    settings: Res<DioxusUiSettings>,
+    pointer_over_ui: Res<PointerOverUi>,
+    mouse_buttons: Option<ResMut<Input<MouseButton>>>,
+    mut ui_input_consumed: ResMut<UiInputConsumed>,
+) {
+    let mut consumed = false;
+    if let (true, true, Some(mut mouse_buttons)) =
+        (settings.consume_input, pointer_over_ui.0, mouse_buttons)
+    {
+        let pressed = mouse_buttons
+            .get_just_pressed()
+            .copied()
+            .collect::<Vec<_>>();
+        for button in pressed {
+            mouse_buttons.clear_just_pressed(button);
+            consumed = true;
+        }
+    }
+    if ui_input_consumed.0 != consumed {
+        ui_input_consumed.0 = consumed;
+    }
+}
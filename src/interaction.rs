@@ -0,0 +1,77 @@
+use crate::parse_attributes::parse_color;
+use bevy::{
+    ecs::{
+        component::Component,
+        query::{Changed, Or},
+        system::Query,
+        world::EntityWorldMut,
+    },
+    render::color::Color,
+    ui::{BackgroundColor, Interaction},
+};
+
+/// Background colors swapped in from `hover_background_color` and `active_background_color`
+/// while the element is hovered or pressed, falling back to `background_color` (`base`).
+#[derive(Component, Default, Clone)]
+pub(crate) struct InteractionBackgroundColors {
+    pub base: Color,
+    pub hover: Option<Color>,
+    pub active: Option<Color>,
+}
+
+impl InteractionBackgroundColors {
+    pub fn set_attribute(&mut self, name: &str, value: Option<&str>) {
+        match name {
+            "hover_background_color" => self.hover = value.map(parse_color),
+            "active_background_color" => self.active = value.map(parse_color),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub(crate) fn is_interaction_attribute(name: &str) -> bool {
+    matches!(name, "hover_background_color" | "active_background_color")
+}
+
+pub(crate) fn set_interaction_attribute(
+    name: &str,
+    value: Option<&str>,
+    entity: &mut EntityWorldMut,
+) {
+    if value.is_some() && !entity.contains::<InteractionBackgroundColors>() {
+        let base = entity
+            .get::<BackgroundColor>()
+            .map(|background_color| background_color.0)
+            .unwrap_or(Color::NONE);
+        entity.insert((
+            InteractionBackgroundColors {
+                base,
+                ..Default::default()
+            },
+            Interaction::default(),
+        ));
+    }
+    if let Some(mut interaction_background_colors) = entity.get_mut::<InteractionBackgroundColors>()
+    {
+        interaction_background_colors.set_attribute(name, value);
+    }
+}
+
+pub fn update_interaction_background_colors(
+    mut elements: Query<
+        (
+            &Interaction,
+            &InteractionBackgroundColors,
+            &mut BackgroundColor,
+        ),
+        Or<(Changed<Interaction>, Changed<InteractionBackgroundColors>)>,
+    >,
+) {
+    for (interaction, colors, mut background_color) in &mut elements {
+        background_color.0 = match interaction {
+            Interaction::Pressed => colors.active.or(colors.hover).unwrap_or(colors.base),
+            Interaction::Hovered => colors.hover.unwrap_or(colors.base),
+            Interaction::None => colors.base,
+        };
+    }
+}
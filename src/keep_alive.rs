@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// Keeps its children mounted while inactive, hidden with `display: none`, so they keep their
+/// state: hook values, partially filled forms and scroll positions.
+///
+/// Switching between components with a `match` unmounts the old branch and mounts the new one
+/// from scratch. Wrap each branch in a `KeepAlive` instead, rendering all of them and activating
+/// the current one, to return to a branch as it was left. Give each a `key` if the set of branches
+/// changes, so Dioxus doesn't hand one branch's state to another.
+#[allow(non_snake_case)]
+pub fn KeepAlive<'a>(cx: Scope<'a, KeepAliveProps<'a>>) -> Element<'a> {
+    let display = if cx.props.active { "flex" } else { "none" };
+
+    render! {
+        node {
+            class: cx.props.class.unwrap_or_default(),
+            display: display,
+            &cx.props.children
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct KeepAliveProps<'a> {
+    active: bool,
+    class: Option<&'a str>,
+    children: Element<'a>,
+}
@@ -1,45 +1,160 @@
+mod accessibility;
 mod apply_mutations;
+mod button;
+mod collapsible;
 pub mod colors;
+mod cursor;
+mod debug_outlines;
+mod default_font;
 mod deferred_system;
+mod diagnostics;
 mod ecs_hooks;
 mod elements;
 #[macro_use]
 mod events;
 #[cfg(feature = "hot_reload")]
 mod hot_reload;
+mod input_capture;
+mod interaction;
+mod keep_alive;
+mod nested_root;
+mod object_fit;
 mod parse_attributes;
+mod pointer_capture;
+mod progress_bar;
+mod radio_group;
+mod render_target;
+mod root_z_index;
+mod scroll;
+mod spinner;
+mod stylesheet;
+mod test_app;
+mod text_inheritance;
+mod text_shadow;
+mod theme;
 mod tick;
+mod toasts;
+mod use_asset_handle;
+mod use_fixed_time;
+mod use_node_ref;
+mod use_persistent;
+mod use_render_count;
+mod use_root_props;
+mod use_shortcut;
 mod use_state_sendable;
+mod use_timer;
 
 use self::{
+    accessibility::{
+        navigate_focus_with_tab, update_accessibility_focus, update_accessibility_nodes,
+    },
     apply_mutations::BevyTemplate,
+    cursor::update_cursor,
+    debug_outlines::apply_debug_outlines,
+    default_font::apply_default_font,
     deferred_system::DeferredSystemRunQueue,
+    diagnostics::nodes_diagnostic,
     ecs_hooks::EcsSubscriptions,
-    events::{generate_mouse_enter_leave_events, EventReaders, MouseEnter, MouseExit},
+    events::{
+        generate_mouse_enter_leave_events, generate_resize_events, generate_wheel_events,
+        DioxusUiEvent, EventReaders, Mount, MouseEnter, MouseExit, RequestDioxusRerender, Resize,
+        Wheel,
+    },
+    input_capture::{consume_ui_input, update_pointer_over_ui, PointerOverUi, UiInputConsumed},
+    interaction::update_interaction_background_colors,
+    object_fit::update_object_fit,
+    pointer_capture::{capture_dragged_pointers, release_pointer_captures, PointerCapture},
+    render_target::{update_render_target_cameras, DioxusUiRenderTarget},
+    root_z_index::apply_root_z_index,
+    scroll::{
+        apply_scroll_offsets, apply_sticky_offsets, drag_scrollbar_thumbs, scroll_with_mouse_wheel,
+        update_scrollbars,
+    },
+    stylesheet::{DioxusStylesheet, DioxusStylesheetLoader, StylesheetEventReader},
+    text_inheritance::inherit_text_styles,
+    text_shadow::update_text_shadows,
+    theme::DioxusTheme,
     tick::tick_dioxus_ui,
+    toasts::{expire_toasts, DioxusToasts},
+    use_node_ref::NodeRefs,
+    use_persistent::{
+        load_persistent_values, save_persistent_writes, DioxusPersistentStorage, PersistentValues,
+    },
 };
 use bevy::{
-    app::{App, Last, Plugin, PreUpdate},
-    ecs::{bundle::Bundle, component::Component, entity::Entity, schedule::IntoSystemConfigs},
-    prelude::Deref,
-    ui::{node_bundles::NodeBundle, ui_focus_system},
-    utils::{EntityHashMap, HashMap},
+    a11y::{ActionRequest, Focus},
+    app::{App, First, Last, Plugin, PostUpdate, PreUpdate, Update},
+    asset::{AssetApp, Handle},
+    diagnostic::RegisterDiagnostic,
+    ecs::{
+        bundle::Bundle,
+        component::Component,
+        entity::Entity,
+        schedule::{
+            common_conditions::resource_exists_and_changed, IntoSystemConfigs,
+            IntoSystemSetConfigs, SystemSet,
+        },
+        system::Resource,
+    },
+    log::{error, Level},
+    prelude::{default, Deref},
+    render::texture::Image,
+    transform::TransformSystem,
+    ui::{node_bundles::NodeBundle, PositionType, Style, UiRect, UiSystem, Val},
+    utils::{EntityHashMap, HashMap, HashSet},
 };
-use dioxus::core::{Element, ElementId, Scope, VirtualDom};
+use bevy_mod_picking::{backends::bevy_ui::BevyUiBackend, focus::HoverMap, picking_core::PickSet};
+use dioxus::core::{Element, ElementId, Scope, ScopeId, VirtualDom};
 
 pub mod prelude {
-    pub use super::deferred_system::use_system_scheduler;
+    pub use super::button::{Button, ButtonProps};
+    pub use super::collapsible::{Collapsible, CollapsibleProps};
+    pub use super::colors::UiColor;
+    pub use super::deferred_system::{
+        use_run_schedule, use_run_system, use_system_scheduler, use_world_effect,
+    };
+    pub use super::diagnostics::{root_nodes_diagnostic_id, DIOXUS_NODES};
     pub use super::ecs_hooks::{
-        use_event_reader, use_query, use_query_filtered, use_resource, use_world,
+        use_children, use_custom_event, use_query, use_query_count, use_query_filtered,
+        use_query_sorted, use_resource, use_resource_expect, use_window, use_world,
+        use_world_scope, WindowSize,
     };
     pub use super::elements::*;
+    pub use super::events::{
+        DioxusUiEvent, PointerDrag, PointerDragEnd, PointerDrop, PointerHover, PointerPress,
+        PointerWheel, RequestDioxusRerender,
+    };
+    pub use super::input_capture::{DioxusUiInputCapture, PointerOverUi, UiInputConsumed};
+    pub use super::keep_alive::{KeepAlive, KeepAliveProps};
+    pub use super::nested_root::{NestedRoot, NestedRootProps, PanicBoundary, PanicBoundaryProps};
+    pub use super::pointer_capture::PointerCapture;
+    pub use super::progress_bar::{ProgressBar, ProgressBarProps};
+    pub use super::radio_group::{Radio, RadioGroup, RadioGroupProps, RadioProps};
+    pub use super::render_target::DioxusUiRenderTarget;
+    pub use super::spinner::{Spinner, SpinnerProps};
+    pub use super::stylesheet::{DioxusStylesheet, DioxusUiStylesheet};
+    pub use super::test_app::DioxusTestApp;
+    pub use super::theme::DioxusTheme;
+    pub use super::toasts::{use_toasts, DioxusToasts, ToastHost, ToastHostProps, UseToasts};
+    pub use super::use_asset_handle::use_asset_handle;
+    pub use super::use_fixed_time::{use_fixed_time, FixedTime};
+    pub use super::use_node_ref::{use_node_ref, UseNodeRef};
+    pub use super::use_persistent::{
+        use_persistent, DioxusPersistentStorage, PersistentFileStorage, PersistentStorage,
+        UsePersistent,
+    };
+    pub use super::use_render_count::use_render_count;
+    pub use super::use_root_props::{use_root_props, DioxusUiRootProps};
+    pub use super::use_shortcut::use_shortcut;
     pub use super::use_state_sendable::*;
-    pub use super::{DioxusUiBundle, DioxusUiPlugin, DioxusUiRoot};
-    pub use bevy_mod_picking::pointer::PointerButton;
+    pub use super::use_timer::use_timer;
+    pub use super::{DioxusUiBundle, DioxusUiPlugin, DioxusUiRoot, DioxusUiSet, DioxusUiSettings};
+    pub use bevy_mod_picking::pointer::{PointerButton, PointerId};
     pub use dioxus;
     pub use dioxus::prelude::{Event as DioxusEvent, *};
 }
 
+/// Renders every [`DioxusUiRoot`], configured by the [`DioxusUiSettings`] resource.
 pub struct DioxusUiPlugin;
 
 impl Plugin for DioxusUiPlugin {
@@ -52,13 +167,143 @@ impl Plugin for DioxusUiPlugin {
         app.init_non_send_resource::<UiContext>()
             .init_resource::<DeferredSystemRunQueue>()
             .init_resource::<EventReaders>()
+            .init_resource::<DioxusTheme>()
+            .init_resource::<DioxusUiSettings>()
+            .init_resource::<NodeRefs>()
+            .init_resource::<PersistentValues>()
+            .init_resource::<PointerOverUi>()
+            .init_resource::<UiInputConsumed>()
+            .init_resource::<PointerCapture>()
+            .init_resource::<DioxusToasts>()
+            .init_resource::<StylesheetEventReader>()
+            .init_asset::<DioxusStylesheet>()
+            .init_asset_loader::<DioxusStylesheetLoader>()
             .add_event::<MouseEnter>()
             .add_event::<MouseExit>()
+            .add_event::<Wheel>()
+            .add_event::<Resize>()
+            .add_event::<Mount>()
+            .add_event::<DioxusUiEvent>()
+            .add_event::<RequestDioxusRerender>()
+            .init_resource::<Focus>()
+            .add_event::<ActionRequest>()
+            .register_diagnostic(nodes_diagnostic())
             .add_systems(
                 PreUpdate,
-                generate_mouse_enter_leave_events.after(ui_focus_system),
+                (
+                    generate_mouse_enter_leave_events.after(PickSet::Input),
+                    (update_pointer_over_ui, consume_ui_input)
+                        .chain()
+                        .after(PickSet::Focus),
+                    scroll_with_mouse_wheel.after(PickSet::Focus),
+                    drag_scrollbar_thumbs.after(PickSet::Focus),
+                    generate_wheel_events.after(PickSet::Focus),
+                    update_cursor.after(PickSet::Focus),
+                    capture_dragged_pointers.after(PickSet::Focus),
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    generate_resize_events.after(UiSystem::Layout),
+                    update_object_fit
+                        .after(UiSystem::Layout)
+                        .before(TransformSystem::TransformPropagate),
+                    (
+                        apply_scroll_offsets,
+                        (apply_sticky_offsets, update_scrollbars),
+                    )
+                        .chain()
+                        .after(UiSystem::Layout)
+                        .before(TransformSystem::TransformPropagate),
+                    update_accessibility_nodes,
+                    update_accessibility_focus,
+                    navigate_focus_with_tab,
+                    apply_root_z_index.before(UiSystem::Stack),
+                    update_render_target_cameras,
+                ),
             )
-            .add_systems(Last, tick_dioxus_ui);
+            .configure_sets(Last, (DioxusUiSet::Tick, DioxusUiSet::Apply).chain())
+            .add_systems(Last, tick_dioxus_ui.in_set(DioxusUiSet::Tick))
+            .add_systems(
+                Last,
+                (
+                    update_interaction_background_colors,
+                    apply_debug_outlines,
+                    apply_default_font,
+                    inherit_text_styles,
+                    update_text_shadows,
+                )
+                    .chain()
+                    .in_set(DioxusUiSet::Apply),
+            )
+            .add_systems(Last, release_pointer_captures.after(DioxusUiSet::Tick))
+            .add_systems(Update, expire_toasts)
+            .add_systems(
+                First,
+                load_persistent_values
+                    .run_if(resource_exists_and_changed::<DioxusPersistentStorage>()),
+            )
+            .add_systems(Last, save_persistent_writes.after(DioxusUiSet::Tick));
+    }
+
+    // Runs once every plugin is added, so the picking plugins can be added after this one
+    fn finish(&self, app: &mut App) {
+        if !app.world.contains_resource::<HoverMap>() {
+            panic!(
+                "bevy_dioxus needs bevy_mod_picking to deliver events, add \
+                 `bevy_mod_picking::DefaultPickingPlugins` to the app."
+            );
+        }
+        if !app.is_plugin_added::<BevyUiBackend>() {
+            error!(
+                "bevy_dioxus event handlers won't fire without bevy_mod_picking's bevy_ui backend, \
+                 add `DefaultPickingPlugins` or `BevyUiBackend`."
+            );
+        }
+    }
+}
+
+/// The system sets bevy_dioxus runs in, in the `Last` schedule. Order systems producing state the
+/// UI should show the same frame before `DioxusUiSet::Tick`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DioxusUiSet {
+    /// Dispatches events to components, renders every root and spawns or updates their entities.
+    Tick,
+    /// Updates the entities from state the renderer keeps, like interaction colors, debug
+    /// outlines, default fonts and text shadows.
+    Apply,
+}
+
+#[derive(Resource)]
+pub struct DioxusUiSettings {
+    /// Outlines every element to show its layout, without affecting it.
+    pub debug_outlines: bool,
+    /// The level to log components that rendered nothing at, or `None` to not log them. Defaults
+    /// to `Level::DEBUG`, as components like [`ToastHost`](prelude::ToastHost) render nothing on
+    /// purpose and dioxus can't tell those apart from errors. Panicking components are always
+    /// logged as errors.
+    pub render_diagnostics: Option<Level>,
+    /// Draws every top-level root at `ZIndex::Global(base_z_index)`, above native bevy_ui nodes
+    /// with a lower global z-index and below those with a higher one. `None` leaves the roots'
+    /// `ZIndex` as spawned, stacking them with other root nodes in spawn order.
+    pub base_z_index: Option<i32>,
+    /// Hides mouse presses over the UI from game systems, see [`UiInputConsumed`].
+    pub consume_input: bool,
+    /// The asset path of the font for every `text` element without a `font` attribute, or `None`
+    /// for Bevy's default font.
+    pub default_font: Option<String>,
+}
+
+impl Default for DioxusUiSettings {
+    fn default() -> Self {
+        Self {
+            debug_outlines: false,
+            render_diagnostics: Some(Level::DEBUG),
+            base_z_index: None,
+            consume_input: false,
+            default_font: None,
+        }
     }
 }
 
@@ -68,13 +313,70 @@ pub struct DioxusUiBundle {
     pub node_bundle: NodeBundle,
 }
 
+impl DioxusUiBundle {
+    /// A root filling the window. Construct the struct directly for full control over the node.
+    pub fn new(root_component: fn(Scope) -> Element) -> Self {
+        Self {
+            dioxus_ui_root: DioxusUiRoot(root_component),
+            node_bundle: NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+        }
+    }
+
+    /// Also renders the UI into `image`, see [`DioxusUiRenderTarget`] for its limitations: the
+    /// image shows every root, not just this one. Call it last, it returns the bundle with the
+    /// render target.
+    pub fn with_render_target(self, image: Handle<Image>) -> (Self, DioxusUiRenderTarget) {
+        (self, DioxusUiRenderTarget(image))
+    }
+
+    pub fn with_size(mut self, width: Val, height: Val) -> Self {
+        self.node_bundle.style.width = width;
+        self.node_bundle.style.height = height;
+        self
+    }
+
+    /// Sizes the root to its content and positions it at `offsets` from the window's edges, e.g.
+    /// a HUD widget in the top right corner with
+    /// `UiRect::new(Val::Auto, Val::Px(8.0), Val::Px(8.0), Val::Auto)`. Edges at `Val::Auto`
+    /// aren't anchored to, and anchoring opposite edges stretches the root between them.
+    ///
+    /// The renderer never changes the root node's style, so roots can be laid out like any other
+    /// node, e.g. with `with_style` or by parenting them to a native bevy_ui node.
+    pub fn anchored(mut self, offsets: UiRect) -> Self {
+        let style = &mut self.node_bundle.style;
+        style.position_type = PositionType::Absolute;
+        style.width = Val::Auto;
+        style.height = Val::Auto;
+        style.left = offsets.left;
+        style.right = offsets.right;
+        style.top = offsets.top;
+        style.bottom = offsets.bottom;
+        self
+    }
+
+    /// Replaces the root node's style, including its size.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.node_bundle.style = style;
+        self
+    }
+}
+
 #[derive(Component, Deref, Hash, PartialEq, Eq, Clone, Copy)]
 pub struct DioxusUiRoot(pub fn(Scope) -> Element);
 
 #[derive(Default)]
 struct UiContext {
     roots: HashMap<(Entity, DioxusUiRoot), UiRoot>,
-    subscriptions: EcsSubscriptions,
+    // Roots whose components panicked, which now show a fallback. For recoverable errors within a
+    // component, use dioxus's `ErrorBoundary` and `Throw` instead.
+    crashed_roots: HashSet<(Entity, DioxusUiRoot)>,
 }
 
 struct UiRoot {
@@ -82,7 +384,11 @@ struct UiRoot {
     element_id_to_bevy_ui_entity: HashMap<ElementId, Entity>,
     bevy_ui_entity_to_element_id: EntityHashMap<Entity, ElementId>,
     templates: HashMap<String, BevyTemplate>,
+    // Declared after virtual_dom so it outlives the hooks that unsubscribe when dropped
+    subscriptions: EcsSubscriptions,
     needs_rebuild: bool,
+    // Scopes that rendered nothing and were already reported
+    aborted_scopes: HashSet<ScopeId>,
 }
 
 impl UiRoot {
@@ -92,7 +398,9 @@ impl UiRoot {
             element_id_to_bevy_ui_entity: HashMap::new(),
             bevy_ui_entity_to_element_id: EntityHashMap::default(),
             templates: HashMap::new(),
+            subscriptions: EcsSubscriptions::default(),
             needs_rebuild: true,
+            aborted_scopes: HashSet::new(),
         }
     }
 }
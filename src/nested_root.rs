@@ -0,0 +1,108 @@
+use crate::{prelude::*, DioxusUiBundle, DioxusUiRoot};
+use bevy::{
+    ecs::{component::Component, entity::Entity, world::World},
+    hierarchy::{BuildWorldChildren, DespawnRecursiveExt},
+    ui::{node_bundles::NodeBundle, Style, Val},
+};
+use std::sync::{Arc, Mutex};
+
+/// Mounts `root` as a separate `DioxusUiRoot` inside this node, e.g. for a plugin's widget that
+/// ships its own dioxus tree.
+///
+/// The nested root has its own virtual dom and contexts, and fills this node. Pointer events
+/// don't bubble out of it into this component's listeners.
+#[allow(non_snake_case)]
+pub fn NestedRoot<'a>(cx: Scope<'a, NestedRootProps<'a>>) -> Element<'a> {
+    let node_ref = use_nested_root(cx, cx.props.root, None);
+
+    render! {
+        node {
+            node_ref: "{node_ref}",
+            class: cx.props.class.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct NestedRootProps<'a> {
+    root: DioxusUiRoot,
+    class: Option<&'a str>,
+}
+
+/// Like [`NestedRoot`], but shows `fallback` in place of `root` if any of its components panic.
+///
+/// A panic tears down the root it happened in and replaces it with an error message. Mounted in a
+/// `PanicBoundary`, that's only `root`, while this component and the rest of its root keep
+/// rendering. Mounting it again with another `root` retries.
+#[allow(non_snake_case)]
+pub fn PanicBoundary<'a>(cx: Scope<'a, PanicBoundaryProps<'a>>) -> Element<'a> {
+    let fallback = cx.props.fallback.map(str::to_owned);
+    let node_ref = use_nested_root(cx, cx.props.root, fallback);
+
+    render! {
+        node {
+            node_ref: "{node_ref}",
+            class: cx.props.class.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct PanicBoundaryProps<'a> {
+    root: DioxusUiRoot,
+    /// The text shown once `root` panicked, instead of the panic message.
+    fallback: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+/// The text a crashed root shows instead of the panic message, from [`PanicBoundary`].
+#[derive(Component)]
+pub(crate) struct PanicFallback(pub String);
+
+/// Spawns `root` as a child of the returned node ref's node while mounted.
+fn use_nested_root(cx: &ScopeState, root: DioxusUiRoot, fallback: Option<String>) -> UseNodeRef {
+    let node_ref = use_node_ref(cx);
+
+    use_world_effect(cx, root, move |scheduler| {
+        // Written by the spawning system, so a cleanup queued before it ran still finds the root
+        let nested_root = Arc::new(Mutex::new(None::<Entity>));
+        let spawned_root = Arc::clone(&nested_root);
+        scheduler.schedule(move |world: &mut World| {
+            let Some(host) = node_ref.entity(world) else {
+                return;
+            };
+            let mut entity = world.spawn(DioxusUiBundle {
+                dioxus_ui_root: root,
+                node_bundle: NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            });
+            if let Some(fallback) = fallback.clone() {
+                entity.insert(PanicFallback(fallback));
+            }
+            let entity = entity.id();
+            match world.get_entity_mut(host) {
+                Some(mut host) => {
+                    host.add_child(entity);
+                    *spawned_root.lock().expect("Lock poisoned") = Some(entity);
+                }
+                None => world.entity_mut(entity).despawn_recursive(),
+            }
+        });
+        move |scheduler| {
+            scheduler.schedule(move |world: &mut World| {
+                let entity = nested_root.lock().expect("Lock poisoned").take();
+                if let Some(entity) = entity.and_then(|entity| world.get_entity_mut(entity)) {
+                    entity.despawn_recursive();
+                }
+            });
+        }
+    });
+
+    node_ref
+}
@@ -0,0 +1,100 @@
+use bevy::{
+    ecs::{
+        component::Component,
+        query::{Changed, Or},
+        system::Query,
+        world::EntityWorldMut,
+    },
+    log::warn,
+    math::Vec2,
+    transform::components::Transform,
+    ui::{widget::UiImageSize, Node},
+};
+
+/// How an `image` element scales its texture from `object_fit`.
+///
+/// bevy_ui always stretches a texture over the whole node, so this scales the node's `Transform`
+/// around its center instead. `cover` overflows the node rather than cropping, so clip it with
+/// `overflow: "clip"` on a parent.
+#[derive(Component, Clone)]
+pub(crate) struct ObjectFit {
+    mode: ObjectFitMode,
+    /// The scale from the `scale` attributes, which the fit is applied on top of.
+    pub scale: Vec2,
+}
+
+#[derive(Clone, Copy)]
+enum ObjectFitMode {
+    Fill,
+    Contain,
+    Cover,
+    None,
+}
+
+impl ObjectFit {
+    pub fn parse(value: &str) -> Self {
+        let mode = match value {
+            "fill" => ObjectFitMode::Fill,
+            "contain" => ObjectFitMode::Contain,
+            "cover" => ObjectFitMode::Cover,
+            "none" => ObjectFitMode::None,
+            _ => {
+                warn!("Encountered unknown bevy_dioxus object_fit `{value}`, using `fill`.");
+                ObjectFitMode::Fill
+            }
+        };
+        Self {
+            mode,
+            scale: Vec2::ONE,
+        }
+    }
+}
+
+pub(crate) fn set_object_fit_attribute(value: Option<&str>, entity: &mut EntityWorldMut) {
+    match value {
+        Some(value) => {
+            let scale = match entity.get::<ObjectFit>() {
+                Some(object_fit) => object_fit.scale,
+                None => entity
+                    .get::<Transform>()
+                    .map(|transform| transform.scale.truncate())
+                    .unwrap_or(Vec2::ONE),
+            };
+            entity.insert(ObjectFit {
+                scale,
+                ..ObjectFit::parse(value)
+            });
+        }
+        None => {
+            if let Some(object_fit) = entity.take::<ObjectFit>() {
+                if let Some(mut transform) = entity.get_mut::<Transform>() {
+                    transform.scale = object_fit.scale.extend(transform.scale.z);
+                }
+            }
+        }
+    }
+}
+
+pub fn update_object_fit(
+    mut images: Query<
+        (&ObjectFit, &Node, &UiImageSize, &mut Transform),
+        Or<(Changed<ObjectFit>, Changed<Node>, Changed<UiImageSize>)>,
+    >,
+) {
+    for (object_fit, node, image_size, mut transform) in &mut images {
+        let node_size = node.size();
+        let image_size = image_size.size();
+        let fit = if node_size.cmple(Vec2::ZERO).any() || image_size.cmple(Vec2::ZERO).any() {
+            Vec2::ONE
+        } else {
+            let stretch = node_size / image_size;
+            match object_fit.mode {
+                ObjectFitMode::Fill => Vec2::ONE,
+                ObjectFitMode::Contain => image_size * stretch.min_element() / node_size,
+                ObjectFitMode::Cover => image_size * stretch.max_element() / node_size,
+                ObjectFitMode::None => image_size / node_size,
+            }
+        };
+        transform.scale = (object_fit.scale * fit).extend(transform.scale.z);
+    }
+}
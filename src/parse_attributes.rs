@@ -1,12 +1,18 @@
 use bevy::{
     asset::{AssetPath, AssetServer},
+    ecs::component::Component,
+    log::warn,
     math::Quat,
+    prelude::default,
     render::{color::Color, view::Visibility},
-    text::{Text, TextAlignment},
+    text::{Text, TextAlignment, TextStyle},
     transform::components::Transform,
     ui::*,
 };
-use std::f32::consts::PI;
+use std::{
+    f32::consts::PI,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 #[allow(clippy::too_many_arguments)]
 pub fn set_attribute(
@@ -20,9 +26,29 @@ pub fn set_attribute(
     visibility: &mut Visibility,
     z_index: &mut ZIndex,
     text: Option<&mut Text>,
+    text_transform: Option<&mut TextTransform>,
     image: Option<&mut UiImage>,
     asset_server: &AssetServer,
 ) {
+    // Interpolated values like `width: "{computed}"` can end up as `NaN` or `inf`, which break layout
+    if name != "text" && value.split_whitespace().any(is_non_finite) {
+        warn!("bevy_dioxus attribute `{name}: {value}` is not finite, using the default instead.");
+        reset_attribute(
+            name,
+            style,
+            border_color,
+            outline,
+            background_color,
+            transform,
+            visibility,
+            z_index,
+            text,
+            text_transform,
+            image,
+        );
+        return;
+    }
+
     #[allow(unused_variables, unreachable_code)]
     match (name, value) {
         ("animate", value) => todo!(),
@@ -31,24 +57,28 @@ pub fn set_attribute(
         ("display", "none") => style.display = Display::None,
         ("position", "relative") => style.position_type = PositionType::Relative,
         ("position", "absolute") => style.position_type = PositionType::Absolute,
+        // `scroll` and `auto` clip here, `crate::scroll` handles the scrolling.
+        // Clipping is always to the rectangular bounds. TODO: Clip to rounded corners once
+        // `border_radius` exists, which needs bevy_ui to support both radii and masked clipping.
         ("overflow", "visible") => style.overflow = Overflow::visible(),
-        ("overflow", "clip") => style.overflow = Overflow::clip(),
+        ("overflow", "clip" | "scroll" | "auto") => style.overflow = Overflow::clip(),
         ("overflow_x", "visible") => style.overflow.x = OverflowAxis::Visible,
-        ("overflow_x", "clip") => style.overflow.x = OverflowAxis::Clip,
+        ("overflow_x", "clip" | "scroll" | "auto") => style.overflow.x = OverflowAxis::Clip,
         ("overflow_y", "visible") => style.overflow.y = OverflowAxis::Visible,
-        ("overflow_y", "clip") => style.overflow.y = OverflowAxis::Clip,
+        ("overflow_y", "clip" | "scroll" | "auto") => style.overflow.y = OverflowAxis::Clip,
         ("left", value) => style.left = parse_val(value),
         ("right", value) => style.right = parse_val(value),
         ("top", value) => style.top = parse_val(value),
         ("bottom", value) => style.bottom = parse_val(value),
-        ("width", value) => style.width = parse_val(value),
-        ("height", value) => style.height = parse_val(value),
-        ("min_width", value) => style.min_width = parse_val(value),
-        ("min_height", value) => style.min_height = parse_val(value),
-        ("max_width", value) => style.max_width = parse_val(value),
-        ("max_height", value) => style.max_height = parse_val(value),
+        ("width", value) => style.width = parse_size_val(value),
+        ("height", value) => style.height = parse_size_val(value),
+        ("min_width", value) => style.min_width = parse_size_val(value),
+        ("min_height", value) => style.min_height = parse_size_val(value),
+        ("max_width", value) => style.max_width = parse_size_val(value),
+        ("max_height", value) => style.max_height = parse_size_val(value),
         ("aspect_ratio", "none") => style.aspect_ratio = None,
         ("aspect_ratio", value) => style.aspect_ratio = Some(parse_f32(value)),
+        // `default` stretches flex items and starts grid items, `stretch` stretches both
         ("align_items", "default") => style.align_items = AlignItems::Default,
         ("align_items", "start") => style.align_items = AlignItems::Start,
         ("align_items", "end") => style.align_items = AlignItems::End,
@@ -118,16 +148,38 @@ pub fn set_attribute(
         ("outline_width", value) => outline.width = parse_val(value),
         ("outline_offset", value) => outline.offset = parse_val(value),
         ("outline_color", value) => outline.color = parse_color(value),
+        ("box_shadow", value) => {
+            let [x, y, blur, color] = value.split_whitespace().collect::<Vec<_>>()[..] else {
+                panic!("Encountered invalid bevy_dioxus box_shadow `{value}`.");
+            };
+            parse_f32(x);
+            parse_f32(y);
+            parse_f32(blur);
+            parse_color(color);
+            static WARNED: AtomicBool = AtomicBool::new(false);
+            warn_once(
+                &WARNED,
+                "bevy_dioxus attribute `box_shadow` is not supported by bevy_ui and will be \
+                 ignored.",
+            );
+        }
         ("flex_direction", "row") => style.flex_direction = FlexDirection::Row,
         ("flex_direction", "column") => style.flex_direction = FlexDirection::Column,
         ("flex_direction", "row_reverse") => style.flex_direction = FlexDirection::RowReverse,
         ("flex_direction", "column_reverse") => style.flex_direction = FlexDirection::ColumnReverse,
+        ("flex_direction", value) => {
+            warn!("Encountered unsupported bevy_dioxus flex_direction `{value}`, using `row` instead.");
+            style.flex_direction = FlexDirection::Row;
+        }
         ("flex_wrap", "no_wrap") => style.flex_wrap = FlexWrap::NoWrap,
         ("flex_wrap", "wrap") => style.flex_wrap = FlexWrap::Wrap,
         ("flex_wrap", "wrap_reverse") => style.flex_wrap = FlexWrap::WrapReverse,
+        ("flex", value) => {
+            (style.flex_grow, style.flex_shrink, style.flex_basis) = parse_flex(value);
+        }
         ("flex_grow", value) => style.flex_grow = parse_f32(value),
         ("flex_shrink", value) => style.flex_shrink = parse_f32(value),
-        ("flex_basis", value) => style.flex_basis = parse_val(value),
+        ("flex_basis", value) => style.flex_basis = parse_size_val(value),
         ("row_gap", value) => style.row_gap = parse_val(value),
         ("column_gap", value) => style.column_gap = parse_val(value),
         ("grid_auto_flow", "row") => style.grid_auto_flow = GridAutoFlow::Row,
@@ -179,7 +231,40 @@ pub fn set_attribute(
             None => *z_index = ZIndex::Local(parse_i32(value)),
             _ => panic!("Encountered invalid bevy_dioxus ZIndex `{value}`."),
         },
-        ("text", value) if text.is_some() => text.unwrap().sections[0].value = value.to_owned(),
+        ("text", value) if text.is_some() => {
+            let text_transform = text_transform.unwrap();
+            text_transform.text = value.to_owned();
+            text.unwrap().sections[0].value = text_transform.apply();
+        }
+        ("text_transform", value) if text.is_some() => {
+            let text_transform = text_transform.unwrap();
+            text_transform.mode = match value {
+                "none" => TextTransformMode::None,
+                "uppercase" => TextTransformMode::Uppercase,
+                "lowercase" => TextTransformMode::Lowercase,
+                "capitalize" => TextTransformMode::Capitalize,
+                _ => panic!("Encountered unsupported bevy_dioxus attribute `{name}: {value}`."),
+            };
+            text.unwrap().sections[0].value = text_transform.apply();
+        }
+        ("white_space", value) if text.is_some() => {
+            let text_transform = text_transform.unwrap();
+            text_transform.white_space = match value {
+                "normal" => WhiteSpace::Normal,
+                "pre" => WhiteSpace::Pre,
+                _ => panic!("Encountered unsupported bevy_dioxus attribute `{name}: {value}`."),
+            };
+            text.unwrap().sections[0].value = text_transform.apply();
+        }
+        ("letter_spacing", value) if text.is_some() => {
+            parse_f32(value);
+            static WARNED: AtomicBool = AtomicBool::new(false);
+            warn_once(
+                &WARNED,
+                "bevy_dioxus attribute `letter_spacing` is not supported by bevy_text and will be \
+                 ignored.",
+            );
+        }
         ("text_direction", "inherit") if text.is_some() => style.direction = Direction::Inherit,
         ("text_direction", "left_to_right") if text.is_some() => {
             style.direction = Direction::LeftToRight;
@@ -199,21 +284,181 @@ pub fn set_attribute(
         ("text_size", value) if text.is_some() => {
             text.unwrap().sections[0].style.font_size = parse_f32(value);
         }
+        ("font", value) if text.is_some() => {
+            text.unwrap().sections[0].style.font = asset_server.load(AssetPath::parse(value));
+        }
         ("text_color", value) if text.is_some() => {
             text.unwrap().sections[0].style.color = parse_color(value);
         }
         ("image_asset_path", value) if image.is_some() => {
             image.unwrap().texture = asset_server.load(AssetPath::parse(value));
         }
+        ("background_repeat", "no_repeat") if image.is_some() => {}
+        ("background_repeat", value) if image.is_some() => {
+            static WARNED: AtomicBool = AtomicBool::new(false);
+            warn_once(
+                &WARNED,
+                &format!(
+                    "Encountered unsupported bevy_dioxus background_repeat `{value}`, images \
+                     always stretch as with `no_repeat`."
+                ),
+            );
+        }
         _ => panic!("Encountered unsupported bevy_dioxus attribute `{name}: {value}`."),
     }
 }
 
-fn parse_color(hex: &str) -> Color {
+/// Resets the fields set by an attribute to their Bevy defaults, for attributes set to `None`.
+#[allow(clippy::too_many_arguments)]
+pub fn reset_attribute(
+    name: &str,
+    style: &mut Style,
+    border_color: &mut BorderColor,
+    outline: &mut Outline,
+    background_color: &mut BackgroundColor,
+    transform: &mut Transform,
+    visibility: &mut Visibility,
+    z_index: &mut ZIndex,
+    text: Option<&mut Text>,
+    text_transform: Option<&mut TextTransform>,
+    image: Option<&mut UiImage>,
+) {
+    let default_style = Style::default();
+    let default_text_style = TextStyle::default();
+    match name {
+        "animate" | "box_shadow" | "letter_spacing" | "background_repeat" => {}
+        "display" => style.display = default_style.display,
+        "position" => style.position_type = default_style.position_type,
+        "overflow" => style.overflow = default_style.overflow,
+        "overflow_x" => style.overflow.x = default_style.overflow.x,
+        "overflow_y" => style.overflow.y = default_style.overflow.y,
+        "left" => style.left = default_style.left,
+        "right" => style.right = default_style.right,
+        "top" => style.top = default_style.top,
+        "bottom" => style.bottom = default_style.bottom,
+        "width" => style.width = default_style.width,
+        "height" => style.height = default_style.height,
+        "min_width" => style.min_width = default_style.min_width,
+        "min_height" => style.min_height = default_style.min_height,
+        "max_width" => style.max_width = default_style.max_width,
+        "max_height" => style.max_height = default_style.max_height,
+        "aspect_ratio" => style.aspect_ratio = default_style.aspect_ratio,
+        "align_items" => style.align_items = default_style.align_items,
+        "justify_items" => style.justify_items = default_style.justify_items,
+        "align_self" => style.align_self = default_style.align_self,
+        "justify_self" => style.justify_self = default_style.justify_self,
+        "align_content" => style.align_content = default_style.align_content,
+        "justify_content" => style.justify_content = default_style.justify_content,
+        "margin" => style.margin = default_style.margin,
+        "margin_left" => style.margin.left = default_style.margin.left,
+        "margin_right" => style.margin.right = default_style.margin.right,
+        "margin_top" => style.margin.top = default_style.margin.top,
+        "margin_bottom" => style.margin.bottom = default_style.margin.bottom,
+        "padding" => style.padding = default_style.padding,
+        "padding_left" => style.padding.left = default_style.padding.left,
+        "padding_right" => style.padding.right = default_style.padding.right,
+        "padding_top" => style.padding.top = default_style.padding.top,
+        "padding_bottom" => style.padding.bottom = default_style.padding.bottom,
+        "border_width" => style.border = default_style.border,
+        "border_width_left" => style.border.left = default_style.border.left,
+        "border_width_right" => style.border.right = default_style.border.right,
+        "border_width_top" => style.border.top = default_style.border.top,
+        "border_width_bottom" => style.border.bottom = default_style.border.bottom,
+        "border_color" => *border_color = BorderColor::default(),
+        "outline_width" => outline.width = Outline::default().width,
+        "outline_offset" => outline.offset = Outline::default().offset,
+        "outline_color" => outline.color = Outline::default().color,
+        "flex_direction" => style.flex_direction = default_style.flex_direction,
+        "flex_wrap" => style.flex_wrap = default_style.flex_wrap,
+        "flex" => {
+            style.flex_grow = default_style.flex_grow;
+            style.flex_shrink = default_style.flex_shrink;
+            style.flex_basis = default_style.flex_basis;
+        }
+        "flex_grow" => style.flex_grow = default_style.flex_grow,
+        "flex_shrink" => style.flex_shrink = default_style.flex_shrink,
+        "flex_basis" => style.flex_basis = default_style.flex_basis,
+        "row_gap" => style.row_gap = default_style.row_gap,
+        "column_gap" => style.column_gap = default_style.column_gap,
+        "grid_auto_flow" => style.grid_auto_flow = default_style.grid_auto_flow,
+        "grid_template_rows" => style.grid_template_rows = default_style.grid_template_rows,
+        "grid_template_columns" => {
+            style.grid_template_columns = default_style.grid_template_columns;
+        }
+        "grid_auto_rows" => style.grid_auto_rows = default_style.grid_auto_rows,
+        "grid_auto_columns" => style.grid_auto_columns = default_style.grid_auto_columns,
+        "grid_row" => style.grid_row = default_style.grid_row,
+        "grid_column" => style.grid_column = default_style.grid_column,
+        // Images are tinted by their background color, so only they default to white
+        "background_color" if image.is_some() => background_color.0 = Color::WHITE,
+        "background_color" => background_color.0 = Color::NONE,
+        "translation" => {
+            transform.translation.x = 0.0;
+            transform.translation.y = 0.0;
+        }
+        "translation_x" => transform.translation.x = 0.0,
+        "translation_y" => transform.translation.y = 0.0,
+        "rotation" => transform.rotation = Quat::IDENTITY,
+        "scale" => {
+            transform.scale.x = 1.0;
+            transform.scale.y = 1.0;
+        }
+        "scale_x" => transform.scale.x = 1.0,
+        "scale_y" => transform.scale.y = 1.0,
+        "visibility" => *visibility = Visibility::default(),
+        "z_index" => *z_index = ZIndex::default(),
+        "text" if text.is_some() => {
+            let text_transform = text_transform.unwrap();
+            text_transform.text = String::new();
+            text.unwrap().sections[0].value = text_transform.apply();
+        }
+        "text_transform" if text.is_some() => {
+            let text_transform = text_transform.unwrap();
+            text_transform.mode = TextTransformMode::default();
+            text.unwrap().sections[0].value = text_transform.apply();
+        }
+        "white_space" if text.is_some() => {
+            let text_transform = text_transform.unwrap();
+            text_transform.white_space = WhiteSpace::default();
+            text.unwrap().sections[0].value = text_transform.apply();
+        }
+        "text_direction" if text.is_some() => style.direction = default_style.direction,
+        "text_multiline_alignment" if text.is_some() => {
+            text.unwrap().alignment = TextAlignment::default();
+        }
+        "text_size" if text.is_some() => {
+            text.unwrap().sections[0].style.font_size = default_text_style.font_size;
+        }
+        "font" if text.is_some() => text.unwrap().sections[0].style.font = default(),
+        "text_color" if text.is_some() => {
+            text.unwrap().sections[0].style.color = default_text_style.color;
+        }
+        "image_asset_path" if image.is_some() => image.unwrap().texture = default(),
+        _ => panic!("Encountered unsupported bevy_dioxus attribute `{name}`."),
+    }
+}
+
+/// Logs attributes that are accepted but have no effect once per process, rather than on every
+/// render setting them.
+fn warn_once(warned: &AtomicBool, message: &str) {
+    if !warned.swap(true, Ordering::Relaxed) {
+        warn!("{message}");
+    }
+}
+
+pub(crate) fn parse_color(hex: &str) -> Color {
     Color::hex(hex).unwrap_or_else(|_| panic!("Encountered invalid bevy_dioxus Color hex `{hex}`."))
 }
 
-fn parse_f32(float: &str) -> f32 {
+fn is_non_finite(value: &str) -> bool {
+    let value = ["px", "vw", "vh", "%"]
+        .into_iter()
+        .find_map(|unit| value.strip_suffix(unit))
+        .unwrap_or(value);
+    value.parse::<f32>().is_ok_and(|value| !value.is_finite())
+}
+
+pub(crate) fn parse_f32(float: &str) -> f32 {
     float
         .parse::<f32>()
         .unwrap_or_else(|val| panic!("Encountered invalid bevy_dioxus f32 `{val}`."))
@@ -224,24 +469,150 @@ fn parse_i32(int: &str) -> i32 {
         .unwrap_or_else(|val| panic!("Encountered invalid bevy_dioxus i32 `{val}`."))
 }
 
+/// Parses `auto`, pixels (`12` or `12px`), viewport units (`50vw`, `50vh`) and percentages (`50%`).
+///
+/// Percentages resolve against the parent node like in CSS, e.g. two `width: "50%"` children
+/// split a row between them. Paddings and margins are percentages of the parent's width on both
+/// axes. CSS grid's `fr` unit has no equivalent here, use `flex_grow` to share free space instead.
 fn parse_val(val: &str) -> Val {
+    try_parse_val(val).unwrap_or_else(|| panic!("Encountered invalid bevy_dioxus Val `{val}`."))
+}
+
+fn try_parse_val(val: &str) -> Option<Val> {
+    if val == "auto" {
+        return Some(Val::Auto);
+    }
     if let Ok(val) = val.parse::<f32>() {
-        return Val::Px(val);
+        return Some(Val::Px(val));
     }
     if let Some((val, "")) = val.split_once("px") {
         if let Ok(val) = val.parse::<f32>() {
-            return Val::Px(val);
+            return Some(Val::Px(val));
         }
     }
     if let Some((val, "")) = val.split_once("vw") {
         if let Ok(val) = val.parse::<f32>() {
-            return Val::Vw(val);
+            return Some(Val::Vw(val));
         }
     }
     if let Some((val, "")) = val.split_once("vh") {
         if let Ok(val) = val.parse::<f32>() {
-            return Val::Vh(val);
+            return Some(Val::Vh(val));
+        }
+    }
+    if let Some((val, "")) = val.split_once('%') {
+        if let Ok(val) = val.parse::<f32>() {
+            return Some(Val::Percent(val));
+        }
+    }
+    None
+}
+
+/// Like [`parse_val`], with the intrinsic sizing keywords `fit_content`, `min_content` and
+/// `max_content`.
+///
+/// bevy_ui's flexbox sizes `auto` nodes to their content, shrunk to the available space, which is
+/// `fit_content`. It has no equivalent of the other two, so they fall back to `auto` with a warning.
+// TODO: Map all three to `GridTrack`s once grid layout attributes exist
+fn parse_size_val(val: &str) -> Val {
+    match val {
+        "fit_content" => Val::Auto,
+        "min_content" | "max_content" => {
+            warn!("bevy_dioxus size `{val}` is not supported by bevy_ui, using `fit_content` instead.");
+            Val::Auto
+        }
+        _ => parse_val(val),
+    }
+}
+
+/// Parses the CSS `flex` shorthand into `flex_grow`, `flex_shrink` and `flex_basis`: `auto`
+/// (`1 1 auto`), `none` (`0 0 auto`), `grow`, `grow shrink` or `grow shrink basis`. Like in CSS,
+/// an omitted shrink is `1` and an omitted basis is `0`. Malformed values fall back to the
+/// defaults (`0 1 auto`) with a warning.
+fn parse_flex(value: &str) -> (f32, f32, Val) {
+    let flex = match value.split_whitespace().collect::<Vec<_>>()[..] {
+        ["auto"] => Some((1.0, 1.0, Val::Auto)),
+        ["none"] => Some((0.0, 0.0, Val::Auto)),
+        [grow] => grow.parse().ok().map(|grow| (grow, 1.0, Val::Px(0.0))),
+        [grow, shrink] => grow
+            .parse()
+            .ok()
+            .zip(shrink.parse().ok())
+            .map(|(grow, shrink)| (grow, shrink, Val::Px(0.0))),
+        [grow, shrink, basis] => grow
+            .parse()
+            .ok()
+            .zip(shrink.parse().ok())
+            .zip(try_parse_val(basis))
+            .map(|((grow, shrink), basis)| (grow, shrink, basis)),
+        _ => None,
+    };
+    flex.unwrap_or_else(|| {
+        warn!("Encountered invalid bevy_dioxus flex `{value}`, using `0 1 auto` instead.");
+        let default_style = Style::default();
+        (
+            default_style.flex_grow,
+            default_style.flex_shrink,
+            default_style.flex_basis,
+        )
+    })
+}
+
+/// The untransformed value of a `text` element's `text` attribute, and the `text_transform` and
+/// `white_space` applied to it.
+#[derive(Component, Clone, Default)]
+pub struct TextTransform {
+    text: String,
+    mode: TextTransformMode,
+    white_space: WhiteSpace,
+}
+
+#[derive(Clone, Copy, Default)]
+enum WhiteSpace {
+    /// Collapses runs of whitespace, including newlines, into a single space.
+    #[default]
+    Normal,
+    /// Preserves newlines and runs of spaces.
+    Pre,
+}
+
+#[derive(Clone, Copy, Default)]
+enum TextTransformMode {
+    #[default]
+    None,
+    Uppercase,
+    Lowercase,
+    Capitalize,
+}
+
+impl TextTransform {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn apply(&self) -> String {
+        let text = match self.white_space {
+            WhiteSpace::Normal => self.text.split_whitespace().collect::<Vec<_>>().join(" "),
+            WhiteSpace::Pre => self.text.clone(),
+        };
+
+        match self.mode {
+            TextTransformMode::None => text,
+            TextTransformMode::Uppercase => text.to_uppercase(),
+            TextTransformMode::Lowercase => text.to_lowercase(),
+            TextTransformMode::Capitalize => {
+                let mut capitalized = String::with_capacity(text.len());
+                let mut word_start = true;
+                for c in text.chars() {
+                    if word_start && c.is_alphabetic() {
+                        capitalized.extend(c.to_uppercase());
+                    } else {
+                        capitalized.push(c);
+                    }
+                    word_start = c.is_whitespace();
+                }
+                capitalized
+            }
         }
     }
-    panic!("Encountered invalid bevy_dioxus Val `{val}`.");
 }
@@ -0,0 +1,72 @@
+use bevy::{
+    ecs::{
+        entity::Entity,
+        event::EventReader,
+        system::{Query, ResMut, Resource},
+    },
+    utils::HashMap,
+};
+use bevy_mod_picking::{
+    events::{DragStart, Pointer},
+    pointer::{PointerId, PointerPress as PickingPointerPress},
+};
+
+/// The node each pointer is captured by. While a pointer is captured, its `ondrag` and
+/// `ondragend` events are sent to the capturing node wherever the pointer is, as if they happened
+/// over it.
+///
+/// Starting a drag implicitly captures the pointer for the node it started on, unless something
+/// already captured it, e.g. a window capturing drags started on its title bar with
+/// [`UseNodeRef::set_pointer_capture`](crate::prelude::UseNodeRef::set_pointer_capture) in
+/// `onmouse_down`. A capture is released once the pointer has no button pressed.
+#[derive(Resource, Default)]
+pub struct PointerCapture {
+    captures: HashMap<PointerId, Entity>,
+}
+
+impl PointerCapture {
+    /// Captures `pointer_id` for `entity`, replacing any previous capture.
+    pub fn set(&mut self, pointer_id: PointerId, entity: Entity) {
+        self.captures.insert(pointer_id, entity);
+    }
+
+    /// Releases `pointer_id`, so its events go to the node under it again.
+    pub fn release(&mut self, pointer_id: PointerId) {
+        self.captures.remove(&pointer_id);
+    }
+
+    /// Returns the node `pointer_id` is captured by, if any.
+    pub fn get(&self, pointer_id: PointerId) -> Option<Entity> {
+        self.captures.get(&pointer_id).copied()
+    }
+}
+
+pub fn capture_dragged_pointers(
+    mut drag_start: EventReader<Pointer<DragStart>>,
+    mut pointer_capture: ResMut<PointerCapture>,
+) {
+    for event in drag_start.read() {
+        pointer_capture
+            .captures
+            .entry(event.pointer_id)
+            .or_insert(event.target);
+    }
+}
+
+/// Runs after the UI ticked, so the final `ondragend` still reaches the capturing node.
+pub fn release_pointer_captures(
+    pointers: Query<(&PointerId, &PickingPointerPress)>,
+    mut pointer_capture: ResMut<PointerCapture>,
+) {
+    if pointer_capture.captures.is_empty() {
+        return;
+    }
+    let pressed = pointers
+        .iter()
+        .filter(|(_, press)| press.is_any_pressed())
+        .map(|(pointer_id, _)| *pointer_id)
+        .collect::<Vec<_>>();
+    pointer_capture
+        .captures
+        .retain(|pointer_id, _| pressed.contains(pointer_id));
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+/// A track with a fill sized to where `value` lies between `min` (default `0`) and `max`
+/// (default `1`), clamped to the track.
+///
+/// The track fills its parent's width unless given a `width`, and is `height` pixels tall
+/// (default `8`). `background_color` defaults to `theme.panel` and `fill_color` to
+/// `theme.accent`.
+#[allow(non_snake_case)]
+pub fn ProgressBar<'a>(cx: Scope<'a, ProgressBarProps<'a>>) -> Element<'a> {
+    let min = cx.props.min.unwrap_or(0.0);
+    let max = cx.props.max.unwrap_or(1.0);
+    let ratio = if max > min {
+        ((cx.props.value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let percent = ratio * 100.0;
+    let height = cx.props.height.unwrap_or(8.0);
+
+    render! {
+        node {
+            width: cx.props.width.unwrap_or("100%"),
+            height: "{height}",
+            background_color: cx.props.background_color.unwrap_or("theme.panel"),
+            node {
+                width: "{percent}%",
+                height: "100%",
+                background_color: cx.props.fill_color.unwrap_or("theme.accent"),
+            }
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct ProgressBarProps<'a> {
+    value: f32,
+    min: Option<f32>,
+    max: Option<f32>,
+    width: Option<&'a str>,
+    height: Option<f32>,
+    background_color: Option<&'a str>,
+    fill_color: Option<&'a str>,
+}
@@ -0,0 +1,84 @@
+use crate::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// Shared between a [`RadioGroup`] and its [`Radio`]s.
+#[derive(Clone, Default)]
+struct RadioGroupContext {
+    value: Rc<RefCell<String>>,
+    // Set by the clicked radio, whose handler runs before the group's as the click bubbles up
+    clicked: Rc<RefCell<Option<String>>>,
+}
+
+/// A single choice between the [`Radio`]s among its children, e.g. a graphics quality setting.
+///
+/// The group is controlled: clicking a radio calls `onchange` with its value, and the parent
+/// passes the new `value` back in.
+#[allow(non_snake_case)]
+pub fn RadioGroup<'a>(cx: Scope<'a, RadioGroupProps<'a>>) -> Element<'a> {
+    let context = use_context_provider(cx, RadioGroupContext::default);
+    *context.value.borrow_mut() = cx.props.value.to_owned();
+    let clicked = Rc::clone(&context.clicked);
+
+    render! {
+        node {
+            class: cx.props.class.unwrap_or_default(),
+            column_gap: "6",
+            onclick: move |_| {
+                let Some(value) = clicked.borrow_mut().take() else {
+                    return;
+                };
+                if value != cx.props.value {
+                    cx.props.onchange.call(value);
+                }
+            },
+            &cx.props.children
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct RadioGroupProps<'a> {
+    value: &'a str,
+    onchange: EventHandler<'a, String>,
+    class: Option<&'a str>,
+    children: Element<'a>,
+}
+
+/// An option of the enclosing [`RadioGroup`], drawn as a [`Button`].
+///
+/// The selected radio uses `selected_color`, defaulting to `theme.accent`, and the others use
+/// `color`, defaulting to the button colors. Hover and click colors are lightened from them.
+#[allow(non_snake_case)]
+pub fn Radio<'a>(cx: Scope<'a, RadioProps<'a>>) -> Element<'a> {
+    let context = use_context::<RadioGroupContext>(cx).expect("Radio must be within a RadioGroup");
+    let selected = *context.value.borrow() == cx.props.value;
+    let (base_color, hover_color, click_color) = match (selected, cx.props.selected_color) {
+        (true, None) => (
+            Some("theme.accent"),
+            Some("theme.accent_hover"),
+            Some("theme.accent_click"),
+        ),
+        (true, Some(selected_color)) => (Some(selected_color), None, None),
+        (false, _) => (cx.props.color, None, None),
+    };
+
+    render! {
+        Button {
+            onclick: move |event: DioxusEvent<PointerButton>| if *event.data == PointerButton::Primary {
+                *context.clicked.borrow_mut() = Some(cx.props.value.to_owned());
+            },
+            base_color: base_color,
+            hover_color: hover_color,
+            click_color: click_color,
+            &cx.props.children
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct RadioProps<'a> {
+    value: &'a str,
+    color: Option<&'a str>,
+    selected_color: Option<&'a str>,
+    children: Element<'a>,
+}
@@ -0,0 +1,60 @@
+use bevy::{
+    asset::Handle,
+    core_pipeline::core_2d::Camera2dBundle,
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::Changed,
+        removal_detection::RemovedComponents,
+        system::{Commands, Local, Query},
+    },
+    hierarchy::DespawnRecursiveExt,
+    prelude::default,
+    render::{
+        camera::{Camera, RenderTarget},
+        texture::Image,
+    },
+    utils::HashMap,
+};
+
+/// Renders the UI into an image, e.g. for a screen in the 3D world, from
+/// `DioxusUiBundle::with_render_target`.
+///
+/// This spawns a UI camera targeting the image for as long as the root exists. bevy_ui can't
+/// target a root to a single camera, so the image shows every root, and this root is still drawn
+/// to the window by its camera too. Roots are laid out against the window's size rather than the
+/// image's, so size the root in pixels. Pointer events over the image don't reach the root yet.
+#[derive(Component, Clone)]
+pub struct DioxusUiRenderTarget(pub Handle<Image>);
+
+/// Spawns and updates the camera of each [`DioxusUiRenderTarget`], and despawns it with its root.
+pub fn update_render_target_cameras(
+    mut commands: Commands,
+    render_targets: Query<(Entity, &DioxusUiRenderTarget), Changed<DioxusUiRenderTarget>>,
+    mut removed_render_targets: RemovedComponents<DioxusUiRenderTarget>,
+    mut cameras: Local<HashMap<Entity, Entity>>,
+) {
+    for root_entity in removed_render_targets.read() {
+        if let Some(camera) = cameras.remove(&root_entity) {
+            commands.entity(camera).despawn_recursive();
+        }
+    }
+
+    for (root_entity, render_target) in &render_targets {
+        if let Some(camera) = cameras.remove(&root_entity) {
+            commands.entity(camera).despawn_recursive();
+        }
+        let camera = commands
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    target: RenderTarget::Image(render_target.0.clone()),
+                    // Before the window's cameras, so their order doesn't clash
+                    order: -1,
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        cameras.insert(root_entity, camera);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{DioxusUiRoot, DioxusUiSettings};
+use bevy::{
+    ecs::{
+        query::{With, Without},
+        system::{Query, Res},
+    },
+    hierarchy::Parent,
+    ui::ZIndex,
+};
+
+// Roots with a parent (e.g. `NestedRoot`s) stay in their parent's stacking context
+pub fn apply_root_z_index(
+    settings: Res<DioxusUiSettings>,
+    mut roots: Query<&mut ZIndex, (With<DioxusUiRoot>, Without<Parent>)>,
+) {
+    let Some(base_z_index) = settings.base_z_index else {
+        return;
+    };
+    for mut z_index in &mut roots {
+        if !matches!(*z_index, ZIndex::Global(z) if z == base_z_index) {
+            *z_index = ZIndex::Global(base_z_index);
+        }
+    }
+}
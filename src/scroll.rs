@@ -0,0 +1,436 @@
+use crate::{events::HasWheelEventListener, parse_attributes::parse_color};
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        event::EventReader,
+        query::{With, Without},
+        system::{Commands, Query, Res},
+        world::EntityWorldMut,
+    },
+    hierarchy::{BuildChildren, Children, DespawnRecursiveExt, Parent},
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    log::warn,
+    math::Vec2,
+    prelude::default,
+    render::color::Color,
+    transform::components::Transform,
+    ui::{
+        node_bundles::NodeBundle, BackgroundColor, Display, Node, PositionType, Style, Val, ZIndex,
+    },
+};
+use bevy_mod_picking::{
+    events::{Drag, Pointer},
+    focus::HoverMap,
+    pointer::PointerId,
+};
+
+/// How far one line of a line-based mouse wheel scrolls, in logical pixels.
+const LINE_HEIGHT: f32 = 20.0;
+
+const SCROLLBAR_WIDTH: f32 = 6.0;
+const MIN_THUMB_HEIGHT: f32 = 16.0;
+const DEFAULT_SCROLLBAR_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.2);
+const DEFAULT_SCROLLBAR_THUMB_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.4);
+
+/// The scroll state of a node with `overflow`, `overflow_x` or `overflow_y` set to `scroll` or
+/// `auto`, which also clip the node like `clip`.
+///
+/// bevy_ui has no scroll offset, so this moves the node's children by offsetting their
+/// `Transform`s after layout. The offset is kept across renders for as long as the node scrolls.
+///
+/// With `scrollbar: "true"`, a vertically scrolling node shows a track along its right edge while
+/// its content overflows, with a thumb sized to the visible part of the content that scrolls it
+/// when dragged. `scrollbar_color` and `scrollbar_thumb_color` color the track and thumb.
+#[derive(Component, Clone, Default)]
+pub(crate) struct Scroll {
+    x: bool,
+    y: bool,
+    offset: Vec2,
+    /// How far the content can scroll, as of the last layout.
+    max_offset: Vec2,
+    scrollbar: bool,
+    scrollbar_color: Option<Color>,
+    scrollbar_thumb_color: Option<Color>,
+}
+
+impl Scroll {
+    pub fn set_attribute(&mut self, name: &str, value: Option<&str>) {
+        let scrolls = matches!(value, Some("scroll" | "auto"));
+        match name {
+            "overflow" => (self.x, self.y) = (scrolls, scrolls),
+            "overflow_x" => self.x = scrolls,
+            "overflow_y" => self.y = scrolls,
+            "scrollbar" => {
+                self.scrollbar = match value {
+                    Some("true") => true,
+                    Some("false") | None => false,
+                    Some(value) => {
+                        warn!(
+                            "Encountered invalid bevy_dioxus scrollbar `{value}`, using `false`."
+                        );
+                        false
+                    }
+                };
+            }
+            "scrollbar_color" => self.scrollbar_color = value.map(parse_color),
+            "scrollbar_thumb_color" => self.scrollbar_thumb_color = value.map(parse_color),
+            _ => unreachable!(),
+        }
+        if !self.x {
+            self.offset.x = 0.0;
+        }
+        if !self.y {
+            self.offset.y = 0.0;
+        }
+    }
+
+    pub fn scrolls(&self) -> bool {
+        self.x || self.y
+    }
+
+    /// Whether any attribute is set, which may be before the node's `overflow` makes it scroll.
+    pub fn is_set(&self) -> bool {
+        self.scrolls()
+            || self.scrollbar
+            || self.scrollbar_color.is_some()
+            || self.scrollbar_thumb_color.is_some()
+    }
+}
+
+pub(crate) fn is_overflow_attribute(name: &str) -> bool {
+    matches!(name, "overflow" | "overflow_x" | "overflow_y")
+}
+
+pub(crate) fn is_scrollbar_attribute(name: &str) -> bool {
+    matches!(
+        name,
+        "scrollbar" | "scrollbar_color" | "scrollbar_thumb_color"
+    )
+}
+
+pub(crate) fn set_scroll_attribute(name: &str, value: Option<&str>, entity: &mut EntityWorldMut) {
+    let mut scroll = entity.get::<Scroll>().cloned().unwrap_or_default();
+    scroll.set_attribute(name, value);
+    if scroll.is_set() {
+        entity.insert(scroll);
+    } else {
+        entity.remove::<Scroll>();
+    }
+}
+
+/// Scrolls the innermost scrolling node under the mouse by the mouse wheel.
+pub fn scroll_with_mouse_wheel(
+    mut mouse_wheel: EventReader<MouseWheel>,
+    hover_map: Res<HoverMap>,
+    mut scrolls: Query<&mut Scroll>,
+    wheel_listeners: Query<(), With<HasWheelEventListener>>,
+    parents: Query<&Parent>,
+) {
+    for event in mouse_wheel.read() {
+        let delta = wheel_delta(event);
+        let Some(hovered) = hover_map.get(&PointerId::Mouse) else {
+            continue;
+        };
+        for entity in hovered.keys() {
+            if let Some(entity) = scrolling_ancestor(*entity, &scrolls, &wheel_listeners, &parents)
+            {
+                let mut scroll = scrolls.get_mut(entity).unwrap();
+                // Clamped to the content once it's laid out
+                scroll.offset -= delta;
+                break;
+            }
+        }
+    }
+}
+
+/// Converts a mouse wheel event to logical pixels.
+pub(crate) fn wheel_delta(event: &MouseWheel) -> Vec2 {
+    match event.unit {
+        MouseScrollUnit::Line => Vec2::new(event.x, event.y) * LINE_HEIGHT,
+        MouseScrollUnit::Pixel => Vec2::new(event.x, event.y),
+    }
+}
+
+fn scrolling_ancestor(
+    mut entity: Entity,
+    scrolls: &Query<&mut Scroll>,
+    wheel_listeners: &Query<(), With<HasWheelEventListener>>,
+    parents: &Query<&Parent>,
+) -> Option<Entity> {
+    loop {
+        if scrolls.get(entity).is_ok_and(Scroll::scrolls) {
+            return Some(entity);
+        }
+        // Nodes with an `onwheel` listener handle the wheel themselves
+        if wheel_listeners.contains(entity) {
+            return None;
+        }
+        entity = parents.get(entity).ok()?.get();
+    }
+}
+
+/// Clamps each scroll offset to the laid out content and moves the children by it.
+pub fn apply_scroll_offsets(
+    mut scrolls: Query<(&mut Scroll, &Node, &Children)>,
+    mut children: Query<(&Node, &mut Transform), Without<ScrollbarTrack>>,
+) {
+    for (mut scroll, node, node_children) in &mut scrolls {
+        // Hidden nodes, e.g. in an inactive `KeepAlive`, lay out empty, keep their offset for later
+        if node.size() == Vec2::ZERO {
+            continue;
+        }
+
+        // Children are positioned relative to the node's center, before the offset is applied
+        let mut content_max = Vec2::NEG_INFINITY;
+        for child in node_children {
+            if let Ok((child_node, transform)) = children.get(*child) {
+                content_max =
+                    content_max.max(transform.translation.truncate() + child_node.size() / 2.0);
+            }
+        }
+        let max_offset = (content_max - node.size() / 2.0).max(Vec2::ZERO);
+        let mut offset = scroll.offset.clamp(Vec2::ZERO, max_offset);
+        if !scroll.x {
+            offset.x = 0.0;
+        }
+        if !scroll.y {
+            offset.y = 0.0;
+        }
+        if scroll.offset != offset {
+            scroll.offset = offset;
+        }
+        if scroll.max_offset != max_offset {
+            scroll.max_offset = max_offset;
+        }
+        if offset == Vec2::ZERO {
+            continue;
+        }
+
+        // Layout resets the children's translations every frame, so this doesn't accumulate
+        for child in node_children {
+            if let Ok((_, mut transform)) = children.get_mut(*child) {
+                transform.translation.x -= offset.x;
+                transform.translation.y -= offset.y;
+            }
+        }
+    }
+}
+
+/// Pins a node with `sticky: "top"` to the top edge of its nearest scrolling ancestor once it's
+/// scrolled past it, until the bottom of its parent reaches it, like CSS's `position: sticky`.
+/// Give it a `z_index` to draw it over the content scrolling underneath.
+#[derive(Component, Clone)]
+pub(crate) struct Sticky;
+
+pub(crate) fn parse_sticky(value: &str) -> Option<Sticky> {
+    match value {
+        "top" => Some(Sticky),
+        "none" => None,
+        _ => {
+            warn!("Encountered invalid bevy_dioxus sticky `{value}`, using `none`.");
+            None
+        }
+    }
+}
+
+pub(crate) fn set_sticky_attribute(value: Option<&str>, entity: &mut EntityWorldMut) {
+    match value.and_then(parse_sticky) {
+        Some(sticky) => entity.insert(sticky),
+        None => entity.remove::<Sticky>(),
+    };
+}
+
+/// Moves sticky nodes down by as much as they were scrolled past the top of their scrolling
+/// ancestor, after the scroll offsets are applied.
+pub fn apply_sticky_offsets(
+    stickies: Query<Entity, With<Sticky>>,
+    mut nodes: Query<(&Node, &mut Transform)>,
+    parents: Query<&Parent>,
+    scrolls: Query<(), With<Scroll>>,
+) {
+    for sticky in &stickies {
+        let Ok(parent) = parents.get(sticky) else {
+            continue;
+        };
+        let parent = parent.get();
+        let Ok((node, transform)) = nodes.get(sticky) else {
+            continue;
+        };
+        let height = node.size().y;
+
+        // Translations are relative to the parent's center, with y pointing down
+        let mut top = transform.translation.y - height / 2.0;
+        let mut parent_bottom = None;
+        let mut ancestor = parent;
+        while !scrolls.contains(ancestor) {
+            let Ok((ancestor_node, ancestor_transform)) = nodes.get(ancestor) else {
+                break;
+            };
+            if ancestor == parent {
+                parent_bottom = Some(ancestor_node.size().y / 2.0);
+            }
+            top += ancestor_transform.translation.y;
+            parent_bottom = parent_bottom.map(|bottom| bottom + ancestor_transform.translation.y);
+            let Ok(next) = parents.get(ancestor) else {
+                break;
+            };
+            ancestor = next.get();
+        }
+        // Not inside a scrolling node
+        if !scrolls.contains(ancestor) {
+            continue;
+        }
+        let Ok((scroll_node, _)) = nodes.get(ancestor) else {
+            continue;
+        };
+
+        let mut shift = -scroll_node.size().y / 2.0 - top;
+        if let Some(parent_bottom) = parent_bottom {
+            shift = shift.min(parent_bottom - (top + height));
+        }
+        if shift > 0.0 {
+            nodes.get_mut(sticky).unwrap().1.translation.y += shift;
+        }
+    }
+}
+
+/// The track of a node's scrollbar, a child of the node that isn't moved by its scroll offset.
+#[derive(Component)]
+pub struct ScrollbarTrack;
+
+#[derive(Component)]
+pub struct ScrollbarThumb;
+
+/// Spawns, sizes and despawns the scrollbars of nodes with `scrollbar: "true"`, after their scroll
+/// offsets are clamped to their content.
+pub fn update_scrollbars(
+    mut commands: Commands,
+    scrolls: Query<(Entity, &Scroll, &Node, Option<&Children>)>,
+    mut tracks: Query<(&mut Style, &mut BackgroundColor, &Children), With<ScrollbarTrack>>,
+    mut thumbs: Query<(&mut Style, &mut BackgroundColor), Without<ScrollbarTrack>>,
+    track_parents: Query<(Entity, &Parent), With<ScrollbarTrack>>,
+) {
+    // Of nodes that stopped scrolling, or had all their attributes removed
+    for (track, parent) in &track_parents {
+        if !scrolls.contains(parent.get()) {
+            commands.entity(track).despawn_recursive();
+        }
+    }
+    for (entity, scroll, node, children) in &scrolls {
+        let track = children.and_then(|children| {
+            children
+                .iter()
+                .copied()
+                .find(|child| tracks.contains(*child))
+        });
+        let Some(track) = track else {
+            if scroll.scrollbar && scroll.y {
+                let track = commands
+                    .spawn((scrollbar_track_bundle(), ScrollbarTrack))
+                    .with_children(|track| {
+                        track.spawn((scrollbar_thumb_bundle(), ScrollbarThumb));
+                    })
+                    .id();
+                commands.entity(entity).add_child(track);
+            }
+            continue;
+        };
+        if !scroll.scrollbar || !scroll.y {
+            commands.entity(track).despawn_recursive();
+            continue;
+        }
+
+        let (mut track_style, mut track_color, track_children) = tracks.get_mut(track).unwrap();
+        let display = if scroll.max_offset.y > 0.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if track_style.display != display {
+            track_style.display = display;
+        }
+        let color = scroll.scrollbar_color.unwrap_or(DEFAULT_SCROLLBAR_COLOR);
+        if track_color.0 != color {
+            track_color.0 = color;
+        }
+        if display == Display::None {
+            continue;
+        }
+
+        let Ok((mut thumb_style, mut thumb_color)) = thumbs.get_mut(track_children[0]) else {
+            continue;
+        };
+        let height = node.size().y;
+        let thumb_height = (height * height / (height + scroll.max_offset.y))
+            .max(MIN_THUMB_HEIGHT)
+            .min(height);
+        let thumb_top = scroll.offset.y / scroll.max_offset.y * (height - thumb_height);
+        if thumb_style.height != Val::Px(thumb_height) {
+            thumb_style.height = Val::Px(thumb_height);
+        }
+        if thumb_style.top != Val::Px(thumb_top) {
+            thumb_style.top = Val::Px(thumb_top);
+        }
+        let color = scroll
+            .scrollbar_thumb_color
+            .unwrap_or(DEFAULT_SCROLLBAR_THUMB_COLOR);
+        if thumb_color.0 != color {
+            thumb_color.0 = color;
+        }
+    }
+}
+
+/// Scrolls the node a dragged scrollbar thumb belongs to, by as much content as the thumb moved
+/// over its track.
+pub fn drag_scrollbar_thumbs(
+    mut drag: EventReader<Pointer<Drag>>,
+    thumbs: Query<&Parent, With<ScrollbarThumb>>,
+    tracks: Query<&Parent, With<ScrollbarTrack>>,
+    mut scrolls: Query<(&mut Scroll, &Node)>,
+) {
+    for event in drag.read() {
+        let Ok(track) = thumbs.get(event.target) else {
+            continue;
+        };
+        let Ok(scrolling_node) = tracks.get(track.get()) else {
+            continue;
+        };
+        let Ok((mut scroll, node)) = scrolls.get_mut(scrolling_node.get()) else {
+            continue;
+        };
+        let height = node.size().y;
+        if height > 0.0 {
+            // Clamped to the content once it's laid out
+            scroll.offset.y += event.delta.y * (height + scroll.max_offset.y) / height;
+        }
+    }
+}
+
+fn scrollbar_track_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(0.0),
+            right: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            width: Val::Px(SCROLLBAR_WIDTH),
+            display: Display::None,
+            ..default()
+        },
+        // Over the scrolled content
+        z_index: ZIndex::Local(1),
+        ..default()
+    }
+}
+
+fn scrollbar_thumb_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            ..default()
+        },
+        ..default()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use bevy::time::Time;
+
+/// Three dots that take turns growing, to show something is loading.
+///
+/// Re-renders every frame while mounted. `size` is the diameter of a dot in pixels (default `8`),
+/// and `color` defaults to `theme.accent`.
+#[allow(non_snake_case)]
+pub fn Spinner<'a>(cx: Scope<'a, SpinnerProps<'a>>) -> Element<'a> {
+    let elapsed_seconds = use_resource_expect::<Time>(cx).elapsed_seconds();
+    let size = cx.props.size.unwrap_or(8.0);
+    let color = cx.props.color.unwrap_or("theme.accent");
+    let active_dot = (elapsed_seconds * 3.0) as usize % 3;
+    let dot_sizes = (0..3).map(|dot| if dot == active_dot { size } else { size / 2.0 });
+
+    render! {
+        node {
+            column_gap: "{size / 2.0}",
+            height: "{size}",
+            align_items: "center",
+            for (dot, dot_size) in dot_sizes.enumerate() {
+                node {
+                    key: "{dot}",
+                    width: "{dot_size}",
+                    height: "{dot_size}",
+                    background_color: color,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct SpinnerProps<'a> {
+    size: Option<f32>,
+    color: Option<&'a str>,
+}
@@ -0,0 +1,123 @@
+use bevy::{
+    asset::{
+        io::Reader, Asset, AssetEvent, AssetLoader, Assets, AsyncReadExt, Handle, LoadContext,
+    },
+    ecs::{component::Component, event::ManualEventReader, system::Resource, world::World},
+    reflect::TypePath,
+    utils::{BoxedFuture, HashMap},
+};
+use serde::Deserialize;
+use std::fmt::{self, Display};
+
+/// Maps class names to the attributes they set, e.g.
+///
+/// ```ron
+/// {
+///     "panel": [("padding", "theme.md"), ("background_color", "theme.panel")],
+///     "column": [("flex_direction", "column")],
+/// }
+/// ```
+///
+/// Load a `.style.ron` file and insert it as [`DioxusUiStylesheet`] to resolve `class: "panel column"`
+/// attributes. Classes are applied in order, and inline attributes always take precedence.
+#[derive(Asset, TypePath, Deserialize, Default)]
+#[serde(transparent)]
+pub struct DioxusStylesheet {
+    pub classes: HashMap<String, Vec<(String, String)>>,
+}
+
+#[derive(Resource)]
+pub struct DioxusUiStylesheet(pub Handle<DioxusStylesheet>);
+
+#[derive(Default)]
+pub struct DioxusStylesheetLoader;
+
+impl AssetLoader for DioxusStylesheetLoader {
+    type Asset = DioxusStylesheet;
+    type Settings = ();
+    type Error = DioxusStylesheetLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(ron::de::from_bytes(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["style.ron"]
+    }
+}
+
+#[derive(Debug)]
+pub enum DioxusStylesheetLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl Display for DioxusStylesheetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Could not read bevy_dioxus stylesheet: {error}"),
+            Self::Ron(error) => write!(f, "Could not parse bevy_dioxus stylesheet: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for DioxusStylesheetLoaderError {}
+
+impl From<std::io::Error> for DioxusStylesheetLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for DioxusStylesheetLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+/// The `class` attribute of an element, and the inline attributes that override it.
+#[derive(Component, Default, Clone)]
+pub(crate) struct Classes {
+    pub classes: String,
+    pub inline_attributes: HashMap<String, String>,
+}
+
+impl Classes {
+    /// Returns the attributes to apply to the element, with class attributes before inline ones.
+    pub fn attributes(&self, world: &World) -> Vec<(String, String)> {
+        let stylesheet = world
+            .get_resource::<DioxusUiStylesheet>()
+            .and_then(|stylesheet| {
+                world
+                    .resource::<Assets<DioxusStylesheet>>()
+                    .get(&stylesheet.0)
+            });
+
+        let mut attributes = Vec::new();
+        if let Some(stylesheet) = stylesheet {
+            for class in self.classes.split_whitespace() {
+                if let Some(class_attributes) = stylesheet.classes.get(class) {
+                    attributes.extend(class_attributes.iter().cloned());
+                }
+            }
+        }
+        attributes.extend(
+            self.inline_attributes
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+        attributes
+    }
+}
+
+#[derive(Resource, Default)]
+pub(crate) struct StylesheetEventReader(pub ManualEventReader<AssetEvent<DioxusStylesheet>>);
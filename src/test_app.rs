@@ -0,0 +1,205 @@
+use crate::{
+    events::{MouseEnter, MouseExit, PointerHover},
+    DioxusUiBundle, DioxusUiPlugin, DioxusUiRoot,
+};
+use bevy::{
+    app::App,
+    asset::{AssetPlugin, Handle},
+    ecs::{entity::Entity, event::Event, world::World},
+    hierarchy::Children,
+    input::mouse::MouseWheel,
+    math::Vec2,
+    reflect::Reflect,
+    render::camera::NormalizedRenderTarget,
+    text::Text,
+    transform::components::GlobalTransform,
+    ui::{node_bundles::NodeBundle, UiStack},
+    MinimalPlugins,
+};
+use bevy_mod_picking::{
+    backend::HitData,
+    events::{Click, Down, Drag, DragEnd, DragStart, Drop, Out, Over, Pointer, Up},
+    focus::HoverMap,
+    pointer::{Location, PointerButton, PointerId},
+};
+use dioxus::core::{Element, Scope};
+use std::fmt::Debug;
+
+/// A headless app (no window or renderer) with a single dioxus root, for asserting on the Bevy UI
+/// tree a component renders in tests.
+///
+/// Call [`DioxusTestApp::update`] after changing the world to let the component re-render, then
+/// inspect the result with [`DioxusTestApp::nodes`] and [`DioxusTestApp::texts`].
+///
+/// There is no picking backend, so pointer events are simulated on a given node with
+/// [`DioxusTestApp::click`], [`DioxusTestApp::press`], [`DioxusTestApp::release`],
+/// [`DioxusTestApp::hover`] and [`DioxusTestApp::unhover`]. They dispatch like picked events,
+/// bubbling up from the node and honoring `stop_propagation`, and update the app afterwards.
+pub struct DioxusTestApp {
+    pub app: App,
+    root_entity: Entity,
+}
+
+impl DioxusTestApp {
+    pub fn new(root_component: fn(Scope) -> Element) -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), DioxusUiPlugin))
+            // Normally provided by bevy_ui, bevy_input and bevy_mod_picking, which need a window
+            .init_resource::<UiStack>()
+            .add_event::<MouseWheel>()
+            .init_resource::<HoverMap>()
+            .add_event::<Pointer<Click>>()
+            .add_event::<Pointer<Down>>()
+            .add_event::<Pointer<Up>>()
+            .add_event::<Pointer<DragStart>>()
+            .add_event::<Pointer<Drag>>()
+            .add_event::<Pointer<DragEnd>>()
+            .add_event::<Pointer<Drop>>()
+            .add_event::<Pointer<Over>>()
+            .add_event::<Pointer<Out>>();
+        let root_entity = app
+            .world
+            .spawn(DioxusUiBundle {
+                dioxus_ui_root: DioxusUiRoot(root_component),
+                node_bundle: NodeBundle::default(),
+            })
+            .id();
+
+        let mut test_app = Self { app, root_entity };
+        test_app.update();
+        test_app
+    }
+
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    pub fn world(&mut self) -> &mut World {
+        &mut self.app.world
+    }
+
+    pub fn root_entity(&self) -> Entity {
+        self.root_entity
+    }
+
+    /// Returns every entity rendered by the root component, depth-first in tree order.
+    pub fn nodes(&self) -> Vec<Entity> {
+        let mut nodes = Vec::new();
+        let mut stack = self.children(self.root_entity);
+        stack.reverse();
+        while let Some(entity) = stack.pop() {
+            nodes.push(entity);
+            stack.extend(self.children(entity).into_iter().rev());
+        }
+        nodes
+    }
+
+    /// Returns the content of every rendered text node, depth-first in tree order.
+    pub fn texts(&self) -> Vec<String> {
+        self.nodes()
+            .into_iter()
+            .filter_map(|entity| self.app.world.get::<Text>(entity))
+            .map(|text| {
+                text.sections
+                    .iter()
+                    .map(|section| section.value.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the first rendered text node showing `text`, depth-first in tree order, e.g. to
+    /// click a button by its label. Pointer events on it bubble up to the button.
+    pub fn node_with_text(&self, text: &str) -> Option<Entity> {
+        let texts = self.texts();
+        self.nodes()
+            .into_iter()
+            .filter(|entity| self.app.world.get::<Text>(*entity).is_some())
+            .zip(texts)
+            .find_map(|(entity, node_text)| (node_text == text).then_some(entity))
+    }
+
+    /// Presses and releases the primary mouse button on `entity`, sending `onclick_down`,
+    /// `onclick_up` and `onclick`.
+    pub fn click(&mut self, entity: Entity) {
+        self.press(entity, PointerButton::Primary);
+        self.release(entity, PointerButton::Primary);
+    }
+
+    /// Presses `button` on `entity` without releasing it.
+    pub fn press(&mut self, entity: Entity, button: PointerButton) {
+        self.send_pointer_event(entity, Down { button, hit: hit() });
+        self.update();
+    }
+
+    /// Releases `button` on `entity`, clicking it like a release after [`DioxusTestApp::press`].
+    pub fn release(&mut self, entity: Entity, button: PointerButton) {
+        self.send_pointer_event(entity, Up { button, hit: hit() });
+        self.send_pointer_event(entity, Click { button, hit: hit() });
+        self.update();
+    }
+
+    /// Moves the mouse onto `entity`, sending `onmouse_over` and `onmouse_enter`.
+    pub fn hover(&mut self, entity: Entity) {
+        self.send_pointer_event(entity, Over { hit: hit() });
+        let hover = self.pointer_hover(entity);
+        self.app.world.send_event(MouseEnter {
+            target: entity,
+            hover,
+        });
+        self.update();
+    }
+
+    /// Moves the mouse off `entity`, sending `onmouse_out` and `onmouse_exit`.
+    pub fn unhover(&mut self, entity: Entity) {
+        self.send_pointer_event(entity, Out { hit: hit() });
+        let hover = self.pointer_hover(entity);
+        self.app.world.send_event(MouseExit {
+            target: entity,
+            hover,
+        });
+        self.update();
+    }
+
+    fn send_pointer_event<E>(&mut self, entity: Entity, event: E)
+    where
+        Pointer<E>: Event,
+        E: Debug + Clone + Reflect,
+    {
+        let location = Location {
+            target: NormalizedRenderTarget::Image(Handle::default()),
+            position: self.position(entity),
+        };
+        self.app
+            .world
+            .send_event(Pointer::new(PointerId::Mouse, location, entity, event));
+    }
+
+    fn pointer_hover(&self, entity: Entity) -> PointerHover {
+        PointerHover {
+            pointer_id: PointerId::Mouse,
+            position: self.position(entity),
+        }
+    }
+
+    /// The node's center, which the simulated pointer is over.
+    fn position(&self, entity: Entity) -> Vec2 {
+        self.app
+            .world
+            .get::<GlobalTransform>(entity)
+            .map(|transform| transform.translation().truncate())
+            .unwrap_or_default()
+    }
+
+    fn children(&self, entity: Entity) -> Vec<Entity> {
+        self.app
+            .world
+            .get::<Children>(entity)
+            .map(|children| children.to_vec())
+            .unwrap_or_default()
+    }
+}
+
+fn hit() -> HitData {
+    HitData::new(Entity::PLACEHOLDER, 0.0, None, None)
+}
@@ -0,0 +1,113 @@
+use crate::{
+    apply_mutations::DioxusNode,
+    parse_attributes::{parse_color, parse_f32},
+    text_shadow::TextShadow,
+};
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{Added, Changed, Or, With},
+        system::Query,
+    },
+    hierarchy::Parent,
+    render::color::Color,
+    text::{Text, TextStyle},
+};
+
+/// The `text_size` and `text_color` attributes of an element.
+///
+/// Like CSS, text that doesn't set them inherits them from the nearest ancestor that does, so a
+/// panel can set one size and color for all of its text.
+#[derive(Component, Clone, Default)]
+pub(crate) struct InheritableTextStyle {
+    pub size: Option<f32>,
+    pub color: Option<Color>,
+}
+
+impl InheritableTextStyle {
+    pub fn set_attribute(&mut self, name: &str, value: Option<&str>) {
+        match name {
+            "text_size" => self.size = value.map(parse_f32),
+            "text_color" => self.color = value.map(parse_color),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.size.is_some() || self.color.is_some()
+    }
+}
+
+pub(crate) fn is_inheritable_text_attribute(name: &str) -> bool {
+    matches!(name, "text_size" | "text_color")
+}
+
+#[allow(clippy::type_complexity)]
+pub fn inherit_text_styles(
+    changed: Query<
+        (),
+        (
+            With<DioxusNode>,
+            Or<(Changed<InheritableTextStyle>, Added<Text>, Changed<Parent>)>,
+        ),
+    >,
+    mut texts: Query<
+        (
+            Entity,
+            &mut Text,
+            Option<&InheritableTextStyle>,
+            Option<&mut TextShadow>,
+        ),
+        With<DioxusNode>,
+    >,
+    styles: Query<&InheritableTextStyle>,
+    parents: Query<&Parent>,
+) {
+    if changed.is_empty() {
+        return;
+    }
+
+    let default_style = TextStyle::default();
+    for (entity, mut text, own_style, text_shadow) in &mut texts {
+        let size = own_style
+            .and_then(|style| style.size)
+            .or_else(|| inherited(entity, |style| style.size, &styles, &parents))
+            .unwrap_or(default_style.font_size);
+        let color = own_style
+            .and_then(|style| style.color)
+            .or_else(|| inherited(entity, |style| style.color, &styles, &parents))
+            .unwrap_or(default_style.color);
+
+        if text.sections[0].style.font_size != size {
+            text.sections[0].style.font_size = size;
+        }
+        // Shadowed text draws the shadow itself, and its text color on a copy
+        match text_shadow {
+            Some(mut text_shadow) if text_shadow.text_color != color => {
+                text_shadow.text_color = color;
+            }
+            Some(_) => {}
+            None if text.sections[0].style.color != color => {
+                text.sections[0].style.color = color;
+            }
+            None => {}
+        }
+    }
+}
+
+fn inherited<T>(
+    entity: Entity,
+    field: impl Fn(&InheritableTextStyle) -> Option<T>,
+    styles: &Query<&InheritableTextStyle>,
+    parents: &Query<&Parent>,
+) -> Option<T> {
+    let mut ancestor = parents.get(entity).ok().map(Parent::get);
+    while let Some(entity) = ancestor {
+        if let Some(value) = styles.get(entity).ok().and_then(&field) {
+            return Some(value);
+        }
+        ancestor = parents.get(entity).ok().map(Parent::get);
+    }
+    None
+}
@@ -0,0 +1,135 @@
+use crate::parse_attributes::parse_color;
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        query::{Changed, Or, With, Without},
+        system::{Commands, Query},
+        world::EntityWorldMut,
+    },
+    hierarchy::{BuildChildren, DespawnRecursiveExt},
+    math::Vec2,
+    render::color::Color,
+    text::Text,
+    ui::{node_bundles::TextBundle, PositionType, Style, Val},
+};
+use bevy_mod_picking::picking_core::Pickable;
+
+/// A single offset shadow from `text_shadow: "x y color"`.
+///
+/// bevy_ui can't draw a node behind its own children, so the element itself draws the shadow in
+/// `color`, and a copy of the text in `text_color` is drawn `offset` up and to the left on top.
+#[derive(Component, Clone)]
+pub(crate) struct TextShadow {
+    pub offset: Vec2,
+    pub color: Color,
+    pub text_color: Color,
+    copy: Option<Entity>,
+}
+
+impl TextShadow {
+    pub fn parse(value: &str) -> Self {
+        let [x, y, color] = value.split_whitespace().collect::<Vec<_>>()[..] else {
+            panic!("Encountered invalid bevy_dioxus text_shadow `{value}`.");
+        };
+        let parse_offset = |offset: &str| {
+            offset.parse::<f32>().unwrap_or_else(|_| {
+                panic!("Encountered invalid bevy_dioxus text_shadow `{value}`.")
+            })
+        };
+        Self {
+            offset: Vec2::new(parse_offset(x), parse_offset(y)),
+            color: parse_color(color),
+            text_color: Color::WHITE,
+            copy: None,
+        }
+    }
+}
+
+#[derive(Component)]
+pub(crate) struct TextShadowCopy;
+
+pub(crate) fn set_text_shadow_attribute(value: Option<&str>, entity: &mut EntityWorldMut) {
+    let Some(value) = value else {
+        let Some(text_shadow) = entity.take::<TextShadow>() else {
+            return;
+        };
+        if let Some(mut text) = entity.get_mut::<Text>() {
+            for section in &mut text.sections {
+                section.style.color = text_shadow.text_color;
+            }
+        }
+        if let Some(copy) = text_shadow.copy {
+            entity.world_scope(|world| {
+                if let Some(copy) = world.get_entity_mut(copy) {
+                    copy.despawn_recursive();
+                }
+            });
+        }
+        return;
+    };
+
+    let mut new_text_shadow = TextShadow::parse(value);
+    match entity.get_mut::<TextShadow>() {
+        Some(mut text_shadow) => {
+            text_shadow.offset = new_text_shadow.offset;
+            text_shadow.color = new_text_shadow.color;
+        }
+        None => {
+            let Some(text) = entity.get::<Text>() else {
+                return;
+            };
+            new_text_shadow.text_color = text.sections[0].style.color;
+            entity.insert(new_text_shadow);
+        }
+    }
+}
+
+pub fn update_text_shadows(
+    mut elements: Query<
+        (Entity, &mut Text, &mut TextShadow),
+        Or<(Changed<Text>, Changed<TextShadow>)>,
+    >,
+    mut copies: Query<(&mut Text, &mut Style), (With<TextShadowCopy>, Without<TextShadow>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut text, mut text_shadow) in &mut elements {
+        let mut copy_text = text.clone();
+        for section in &mut copy_text.sections {
+            section.style.color = text_shadow.text_color;
+        }
+        let copy_style = Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-text_shadow.offset.x),
+            top: Val::Px(-text_shadow.offset.y),
+            ..Default::default()
+        };
+
+        match text_shadow.copy.and_then(|copy| copies.get_mut(copy).ok()) {
+            Some((mut text, mut style)) => {
+                *text = copy_text;
+                *style = copy_style;
+            }
+            None => {
+                let copy = commands
+                    .spawn((
+                        TextBundle {
+                            text: copy_text,
+                            style: copy_style,
+                            ..Default::default()
+                        },
+                        TextShadowCopy,
+                        Pickable::IGNORE,
+                    ))
+                    .id();
+                commands.entity(entity).add_child(copy);
+                text_shadow.bypass_change_detection().copy = Some(copy);
+            }
+        }
+
+        // Only the color changes, which doesn't need the text to be laid out again
+        for section in &mut text.bypass_change_detection().sections {
+            section.style.color = text_shadow.color;
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::colors::*;
+use bevy::{
+    ecs::{component::Component, system::Resource},
+    utils::HashMap,
+};
+
+/// Named values that attributes can reference with a `theme.` prefix, e.g.
+/// `background_color: "theme.panel"` or `padding: "theme.md"`.
+///
+/// Insert your own `DioxusTheme` before adding `DioxusUiPlugin` to override the default.
+#[derive(Resource, Clone)]
+pub struct DioxusTheme {
+    pub colors: HashMap<String, String>,
+    pub spacing: HashMap<String, String>,
+}
+
+impl DioxusTheme {
+    pub fn resolve<'a>(&'a self, value: &'a str) -> &'a str {
+        match value.strip_prefix("theme.") {
+            Some(key) => self
+                .colors
+                .get(key)
+                .or_else(|| self.spacing.get(key))
+                .map(String::as_str)
+                .unwrap_or_else(|| {
+                    panic!("Encountered unknown bevy_dioxus theme value `{value}`.")
+                }),
+            None => value,
+        }
+    }
+}
+
+impl Default for DioxusTheme {
+    fn default() -> Self {
+        let colors = [
+            ("background", NEUTRAL_900),
+            ("panel", NEUTRAL_800),
+            ("button", NEUTRAL_800),
+            ("button_hover", NEUTRAL_600),
+            ("button_click", NEUTRAL_500),
+            ("accent", VIOLET_700),
+            ("accent_hover", VIOLET_500),
+            ("accent_click", VIOLET_400),
+            ("text", NEUTRAL_50),
+            ("text_muted", NEUTRAL_400),
+        ];
+        let spacing = [
+            ("xs", "2"),
+            ("sm", "4"),
+            ("md", "8"),
+            ("lg", "16"),
+            ("xl", "24"),
+        ];
+
+        Self {
+            colors: colors
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect(),
+            spacing: spacing
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn is_theme_value(value: &str) -> bool {
+    value.starts_with("theme.")
+}
+
+/// Attributes on an entity that reference the theme, re-applied when `DioxusTheme` changes.
+#[derive(Component, Default, Clone)]
+pub(crate) struct ThemedAttributes(pub HashMap<String, String>);
@@ -1,9 +1,13 @@
 use crate::{
-    apply_mutations::apply_mutations,
+    apply_mutations::{apply_classes, apply_mutations, apply_theme},
     deferred_system::DeferredSystemRunQueue,
-    ecs_hooks::EcsContext,
+    diagnostics::measure_nodes,
+    ecs_hooks::{reset_world_borrowers, EcsContext},
     events::{bubble_event, EventReaders},
-    DioxusUiRoot, UiContext, UiRoot,
+    nested_root::PanicFallback,
+    stylesheet::{DioxusUiStylesheet, StylesheetEventReader},
+    theme::DioxusTheme,
+    DioxusUiRoot, DioxusUiSettings, UiContext, UiRoot,
 };
 use bevy::{
     asset::AssetServer,
@@ -11,11 +15,31 @@ use bevy::{
         entity::Entity,
         world::{Mut, World},
     },
+    hierarchy::{BuildWorldChildren, DespawnRecursiveExt},
+    log::{debug, error, info, trace, warn, Level},
+    text::TextStyle,
+    ui::node_bundles::TextBundle,
     utils::HashMap,
 };
-use std::{any::Any, mem, rc::Rc};
+use dioxus::core::{RenderReturn, ScopeId};
+use std::{
+    any::Any,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
 
+/// Each tick:
+/// 1. Tears down roots that were despawned, then runs systems scheduled while rendering last tick.
+/// 2. Dispatches Bevy events to every root's event handlers.
+/// 3. Runs systems the event handlers scheduled, all at once.
+/// 4. Marks components dirty from the hooks' change detection.
+/// 5. Renders each root's virtual dom, during which the world isn't mutated, so every hook reads
+///    the same state.
+/// 6. Spawns and updates Bevy entities from the resulting mutations.
+/// 7. Measures how many entities each root manages.
 pub fn tick_dioxus_ui(world: &mut World) {
+    tear_down_removed_roots(world);
     run_deferred_systems(world);
 
     let ui_events = world.resource_scope(|world, mut event_readers: Mut<EventReaders>| {
@@ -27,8 +51,24 @@ pub fn tick_dioxus_ui(world: &mut World) {
             world.resource(),
             world.resource(),
             world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world.resource(),
+            world,
         )
     });
+    let custom_events = world.resource_scope(|world, mut event_readers: Mut<EventReaders>| {
+        event_readers.read_custom_events(world.resource())
+    });
+    let rerender_requests = world.resource_scope(|world, mut event_readers: Mut<EventReaders>| {
+        event_readers.read_rerender_requests(world.resource())
+    });
 
     let root_entities: HashMap<Entity, DioxusUiRoot> = world
         .query::<(Entity, &DioxusUiRoot)>()
@@ -36,51 +76,221 @@ pub fn tick_dioxus_ui(world: &mut World) {
         .map(|(entity, ui_root)| (entity, *ui_root))
         .collect();
     let mut ui_roots = mem::take(&mut world.non_send_resource_mut::<UiContext>().roots);
+    let theme_changed = world.is_resource_changed::<DioxusTheme>();
+    let stylesheet_changed = world.is_resource_changed::<DioxusUiStylesheet>()
+        | world.resource_scope(|world, mut event_reader: Mut<StylesheetEventReader>| {
+            event_reader.0.read(world.resource()).count() > 0
+        });
 
+    let mut ui_roots_to_render = Vec::new();
     for (root_entity, dioxus_ui_root) in root_entities {
+        if world
+            .non_send_resource::<UiContext>()
+            .crashed_roots
+            .contains(&(root_entity, dioxus_ui_root))
+        {
+            continue;
+        }
+
         let mut ui_root = ui_roots
             .remove(&(root_entity, dioxus_ui_root))
             .unwrap_or_else(|| UiRoot::new(dioxus_ui_root));
 
-        dispatch_ui_events(&ui_events, &mut ui_root, world);
+        if theme_changed {
+            world.resource_scope(|world, asset_server: Mut<AssetServer>| {
+                for template in ui_root.templates.values_mut() {
+                    template.apply_theme(world.resource(), &asset_server);
+                }
+            });
+        }
+
+        if let Err(message) = dispatch_ui_events(&ui_events, &mut ui_root, world) {
+            crash_root(root_entity, dioxus_ui_root, ui_root, &message, world);
+            continue;
+        }
+        ui_roots_to_render.push((root_entity, dioxus_ui_root, ui_root));
+    }
+
+    // Apply everything the event handlers of every root scheduled before any root renders, so
+    // renders show their combined result this tick rather than partial state split over two
+    run_deferred_systems(world);
 
-        schedule_ui_renders_from_ecs_subscriptions(&mut ui_root, world);
+    for (root_entity, dioxus_ui_root, mut ui_root) in ui_roots_to_render {
+        // Despawned by a scheduled system, e.g. a `NestedRoot` being unmounted
+        if world.get_entity(root_entity).is_none() {
+            tear_down_root(root_entity, ui_root, world);
+            continue;
+        }
 
-        render_ui(root_entity, &mut ui_root, world);
+        if rerender_requests.contains(&root_entity) {
+            let root_scope_id = ui_root.virtual_dom.base_scope().scope_id();
+            ui_root.virtual_dom.mark_dirty(root_scope_id);
+        }
+        schedule_ui_renders_from_ecs_subscriptions(
+            root_entity,
+            &custom_events,
+            &mut ui_root,
+            world,
+        );
+        if let Err(message) = render_ui(root_entity, &mut ui_root, world) {
+            crash_root(root_entity, dioxus_ui_root, ui_root, &message, world);
+            continue;
+        }
 
         world
             .non_send_resource_mut::<UiContext>()
             .roots
             .insert((root_entity, dioxus_ui_root), ui_root);
     }
+
+    if theme_changed || stylesheet_changed {
+        world.resource_scope(|world, asset_server: Mut<AssetServer>| {
+            world.resource_scope(|world, theme: Mut<DioxusTheme>| {
+                if theme_changed {
+                    apply_theme(world, &theme, &asset_server);
+                }
+                apply_classes::<()>(world, &theme, &asset_server);
+            });
+        });
+    }
+
+    measure_nodes(world);
+}
+
+/// Tears down the roots whose entity was despawned, or given another root component, since they
+/// last rendered.
+fn tear_down_removed_roots(world: &mut World) {
+    let mut crashed_roots =
+        mem::take(&mut world.non_send_resource_mut::<UiContext>().crashed_roots);
+    crashed_roots.retain(|(root_entity, dioxus_ui_root)| {
+        world.get::<DioxusUiRoot>(*root_entity) == Some(dioxus_ui_root)
+    });
+    world.non_send_resource_mut::<UiContext>().crashed_roots = crashed_roots;
+
+    let removed_roots = world
+        .non_send_resource::<UiContext>()
+        .roots
+        .keys()
+        .filter(|(root_entity, dioxus_ui_root)| {
+            world.get::<DioxusUiRoot>(*root_entity) != Some(dioxus_ui_root)
+        })
+        .copied()
+        .collect::<Vec<_>>();
+    for (root_entity, dioxus_ui_root) in removed_roots {
+        let ui_root = world
+            .non_send_resource_mut::<UiContext>()
+            .roots
+            .remove(&(root_entity, dioxus_ui_root))
+            .unwrap();
+        tear_down_root(root_entity, ui_root, world);
+    }
+}
+
+/// Discards the systems the root's components scheduled that haven't run yet, as writing to their
+/// state or reading their entities would be pointless or wrong, then drops the root.
+fn tear_down_root(root_entity: Entity, ui_root: UiRoot, world: &mut World) {
+    world
+        .resource_mut::<DeferredSystemRunQueue>()
+        .cancel_root_systems(root_entity);
+    // Unmounts every component, whose cleanups may schedule more systems
+    drop(ui_root);
 }
 
 fn run_deferred_systems(world: &mut World) {
-    for mut system in mem::take(&mut *world.resource_mut::<DeferredSystemRunQueue>().run_queue) {
+    let run_queue = mem::take(
+        world
+            .resource_mut::<DeferredSystemRunQueue>()
+            .run_queue
+            .get_mut()
+            .expect("Lock poisoned"),
+    );
+    for (_, mut system) in run_queue {
         system.initialize(world);
         system.run((), world);
     }
 }
 
+/// Runs code that calls into components, catching panics so that only the root is torn down.
+fn catch_component_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic".to_owned())
+    })
+}
+
+fn crash_root(
+    root_entity: Entity,
+    dioxus_ui_root: DioxusUiRoot,
+    ui_root: UiRoot,
+    message: &str,
+    world: &mut World,
+) {
+    error!("bevy_dioxus root {root_entity:?} panicked and was replaced with a fallback: {message}");
+
+    world
+        .resource_mut::<DeferredSystemRunQueue>()
+        .cancel_root_systems(root_entity);
+    // Dropping the half-updated virtual dom unmounts its components, which may panic again. The
+    // systems their cleanups schedule still run, e.g. despawning a `NestedRoot`'s root.
+    if let Err(message) = catch_component_panic(|| drop(ui_root)) {
+        error!("bevy_dioxus root {root_entity:?} panicked again while unmounting: {message}");
+    }
+    world
+        .non_send_resource_mut::<UiContext>()
+        .crashed_roots
+        .insert((root_entity, dioxus_ui_root));
+
+    let fallback_text = match world.get::<PanicFallback>(root_entity) {
+        Some(PanicFallback(text)) => text.clone(),
+        None => format!("UI error: {message}"),
+    };
+    let fallback = world
+        .spawn(TextBundle::from_section(
+            fallback_text,
+            TextStyle::default(),
+        ))
+        .id();
+    let mut root = world.entity_mut(root_entity);
+    root.despawn_descendants();
+    root.add_child(fallback);
+}
+
 fn dispatch_ui_events(
     events: &Vec<(Entity, &str, Rc<dyn Any>, bool)>,
     ui_root: &mut UiRoot,
     world: &World,
-) {
+) -> Result<(), String> {
     for (mut target, name, data, bubbles) in events {
+        // Only find the nearest listener here, dioxus bubbles it further. Components forwarding the
+        // event through an `EventHandler` share its propagation flag, so `stop_propagation()`
+        // inside a child component also stops ancestor listeners outside of it.
         if *bubbles {
             bubble_event(name, &mut target, world);
         }
         if let Some(target_element_id) = ui_root.bevy_ui_entity_to_element_id.get(&target) {
-            ui_root
-                .virtual_dom
-                .handle_event(name, Rc::clone(data), *target_element_id, *bubbles);
+            catch_component_panic(|| {
+                ui_root.virtual_dom.handle_event(
+                    name,
+                    Rc::clone(data),
+                    *target_element_id,
+                    *bubbles,
+                );
+            })?;
         }
     }
+    Ok(())
 }
 
-fn schedule_ui_renders_from_ecs_subscriptions(ui_root: &mut UiRoot, world: &World) {
-    let ecs_subscriptions = &world.non_send_resource::<UiContext>().subscriptions;
+fn schedule_ui_renders_from_ecs_subscriptions(
+    root_entity: Entity,
+    custom_events: &[(Entity, String)],
+    ui_root: &mut UiRoot,
+    world: &World,
+) {
+    let ecs_subscriptions = &mut ui_root.subscriptions;
 
     for scope_id in &*ecs_subscriptions.world_and_queries {
         ui_root.virtual_dom.mark_dirty(*scope_id);
@@ -101,43 +311,128 @@ fn schedule_ui_renders_from_ecs_subscriptions(ui_root: &mut UiRoot, world: &Worl
             }
         }
     }
+
+    for (scope_id, watched_values) in ecs_subscriptions.watched_values.iter_mut() {
+        let mut value_changed = false;
+        for watched_value_changed in watched_values {
+            value_changed |= watched_value_changed(world);
+        }
+        if value_changed {
+            ui_root.virtual_dom.mark_dirty(*scope_id);
+        }
+    }
+
+    for (_, name) in custom_events
+        .iter()
+        .filter(|(root, _)| *root == root_entity)
+    {
+        if let Some(scope_ids) = ecs_subscriptions.custom_events.get(name) {
+            for scope_id in scope_ids {
+                ui_root.virtual_dom.mark_dirty(*scope_id);
+            }
+        }
+    }
 }
 
-fn render_ui(root_entity: Entity, ui_root: &mut UiRoot, world: &mut World) {
+fn render_ui(root_entity: Entity, ui_root: &mut UiRoot, world: &mut World) -> Result<(), String> {
+    reset_world_borrowers();
     ui_root
         .virtual_dom
         .base_scope()
-        .provide_context(EcsContext { world });
+        .provide_context(EcsContext {
+            root_entity,
+            world,
+            subscriptions: &mut ui_root.subscriptions,
+        });
 
     #[cfg(feature = "hot_reload")]
     crate::hot_reload::update_templates(world, &mut ui_root.virtual_dom);
 
     if ui_root.needs_rebuild {
-        let mutations = ui_root.virtual_dom.rebuild();
+        let mutations = catch_component_panic(|| ui_root.virtual_dom.rebuild())?;
+        // Rebuilding renders every scope. The dom is fresh, so their ids are consecutive.
+        let rendered_scopes = (0..)
+            .map(ScopeId)
+            .take_while(|scope_id| ui_root.virtual_dom.get_scope(*scope_id).is_some())
+            .collect::<Vec<_>>();
+        // Mutations can panic too, e.g. on an invalid attribute value
         world.resource_scope(|world, asset_server: Mut<AssetServer>| {
-            apply_mutations(
-                mutations,
-                &mut ui_root.element_id_to_bevy_ui_entity,
-                &mut ui_root.bevy_ui_entity_to_element_id,
-                &mut ui_root.templates,
-                root_entity,
-                world,
-                &asset_server,
-            );
-        });
+            world.resource_scope(|world, theme: Mut<DioxusTheme>| {
+                catch_component_panic(|| {
+                    apply_mutations(
+                        mutations,
+                        &mut ui_root.element_id_to_bevy_ui_entity,
+                        &mut ui_root.bevy_ui_entity_to_element_id,
+                        &mut ui_root.templates,
+                        root_entity,
+                        world,
+                        &theme,
+                        &asset_server,
+                    );
+                })
+            })
+        })?;
         ui_root.needs_rebuild = false;
+        report_render_diagnostics(root_entity, &rendered_scopes, ui_root, world);
     }
 
-    let mutations = ui_root.virtual_dom.render_immediate();
+    let mutations = catch_component_panic(|| ui_root.virtual_dom.render_immediate())?;
+    let rendered_scopes = mutations.dirty_scopes.iter().copied().collect::<Vec<_>>();
     world.resource_scope(|world, asset_server: Mut<AssetServer>| {
-        apply_mutations(
-            mutations,
-            &mut ui_root.element_id_to_bevy_ui_entity,
-            &mut ui_root.bevy_ui_entity_to_element_id,
-            &mut ui_root.templates,
-            root_entity,
-            world,
-            &asset_server,
+        world.resource_scope(|world, theme: Mut<DioxusTheme>| {
+            catch_component_panic(|| {
+                apply_mutations(
+                    mutations,
+                    &mut ui_root.element_id_to_bevy_ui_entity,
+                    &mut ui_root.bevy_ui_entity_to_element_id,
+                    &mut ui_root.templates,
+                    root_entity,
+                    world,
+                    &theme,
+                    &asset_server,
+                );
+            })
+        })
+    })?;
+    report_render_diagnostics(root_entity, &rendered_scopes, ui_root, world);
+    Ok(())
+}
+
+/// Logs components that rendered nothing, which otherwise fail silently, e.g. a root component
+/// returning `None` or throwing an error outside any `ErrorBoundary`. Each is logged once until
+/// it renders something again, so components that render nothing on purpose don't flood the log.
+fn report_render_diagnostics(
+    root_entity: Entity,
+    rendered_scopes: &[ScopeId],
+    ui_root: &mut UiRoot,
+    world: &World,
+) {
+    let Some(level) = world.resource::<DioxusUiSettings>().render_diagnostics else {
+        return;
+    };
+    for scope_id in rendered_scopes {
+        let Some(scope) = ui_root.virtual_dom.get_scope(*scope_id) else {
+            continue;
+        };
+        if !matches!(scope.root_node(), RenderReturn::Aborted(_)) {
+            ui_root.aborted_scopes.remove(scope_id);
+            continue;
+        }
+        if !ui_root.aborted_scopes.insert(*scope_id) {
+            continue;
+        }
+
+        let message = format!(
+            "Component `{}` of bevy_dioxus root {root_entity:?} rendered nothing. It returned \
+            `None` or threw an error outside of an `ErrorBoundary`.",
+            scope.name()
         );
-    });
+        match level {
+            Level::ERROR => error!("{message}"),
+            Level::WARN => warn!("{message}"),
+            Level::INFO => info!("{message}"),
+            Level::DEBUG => debug!("{message}"),
+            _ => trace!("{message}"),
+        }
+    }
 }
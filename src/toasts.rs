@@ -0,0 +1,192 @@
+use crate::{
+    colors::{with_alpha, UiColor},
+    parse_attributes::parse_color,
+    prelude::*,
+    DioxusUiBundle,
+};
+use bevy::{
+    ecs::{
+        entity::Entity,
+        system::{Res, ResMut, Resource},
+        world::World,
+    },
+    hierarchy::DespawnRecursiveExt,
+    render::color::Color,
+    time::Time,
+    ui::{UiRect, Val},
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// How long toasts take to fade out at the end of their duration.
+const FADE_OUT: Duration = Duration::from_millis(300);
+
+/// The transient notifications a [`ToastHost`] shows, e.g. "Saved!". Push to it from systems, or
+/// from components with [`use_toasts`].
+#[derive(Resource, Default)]
+pub struct DioxusToasts {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+#[derive(Clone, PartialEq)]
+struct Toast {
+    id: u64,
+    message: String,
+    remaining: Duration,
+}
+
+impl DioxusToasts {
+    /// Shows `message` for `duration`, after the toasts already showing.
+    pub fn push(&mut self, message: impl Into<String>, duration: Duration) {
+        self.toasts.push(Toast {
+            id: self.next_id,
+            message: message.into(),
+            remaining: duration,
+        });
+        self.next_id += 1;
+    }
+}
+
+/// Counts down every toast, removing them once they expire. Only marks [`DioxusToasts`] changed
+/// while a toast fades out or expires, so hosts don't re-render every frame a toast is shown.
+pub fn expire_toasts(time: Res<Time>, mut toasts: ResMut<DioxusToasts>) {
+    if toasts.toasts.is_empty() {
+        return;
+    }
+    let mut changed = false;
+    for toast in &mut toasts.bypass_change_detection().toasts {
+        toast.remaining = toast.remaining.saturating_sub(time.delta());
+        changed |= toast.remaining < FADE_OUT;
+    }
+    if changed {
+        toasts.toasts.retain(|toast| !toast.remaining.is_zero());
+    }
+}
+
+/// Queues toasts for the app's [`ToastHost`].
+#[derive(Clone, Copy)]
+pub struct UseToasts {
+    scheduler: DeferredSystemScheduler,
+}
+
+impl UseToasts {
+    /// Shows `message` for `duration` once the scheduled systems run, later this tick.
+    pub fn push(&self, message: impl Into<String>, duration: Duration) {
+        let message = message.into();
+        self.scheduler.schedule(move |world: &mut World| {
+            world
+                .resource_mut::<DioxusToasts>()
+                .push(message.clone(), duration);
+        });
+    }
+}
+
+pub fn use_toasts(cx: &ScopeState) -> UseToasts {
+    UseToasts {
+        scheduler: use_system_scheduler(cx),
+    }
+}
+
+/// Shows the [`DioxusToasts`] in a corner of the window while mounted, fading each out as it
+/// expires. Mount one, e.g. in the app's root component.
+///
+/// The toasts are rendered by a separate root anchored to the window, wherever this is mounted,
+/// so they aren't clipped or laid out by this component's ancestors. `corner` is one of
+/// `top_left`, `top_right`, `bottom_left` and `bottom_right` (the default).
+#[allow(non_snake_case)]
+pub fn ToastHost<'a>(cx: Scope<'a, ToastHostProps<'a>>) -> Element<'a> {
+    let corner = cx.props.corner.unwrap_or("bottom_right").to_owned();
+    use_world_effect(cx, corner.clone(), move |scheduler| {
+        // Written by the spawning system, so a cleanup queued before it ran still finds the root
+        let toast_root = Arc::new(Mutex::new(None::<Entity>));
+        let spawned_root = Arc::clone(&toast_root);
+        scheduler.schedule(move |world: &mut World| {
+            let margin = Val::Px(8.0);
+            let (top, bottom) = match corner.starts_with("top") {
+                true => (margin, Val::Auto),
+                false => (Val::Auto, margin),
+            };
+            let (left, right) = match corner.ends_with("left") {
+                true => (margin, Val::Auto),
+                false => (Val::Auto, margin),
+            };
+            let entity = world
+                .spawn((
+                    DioxusUiBundle::new(ToastList).anchored(UiRect::new(left, right, top, bottom)),
+                    DioxusUiRootProps::new(corner.clone()),
+                ))
+                .id();
+            *spawned_root.lock().expect("Lock poisoned") = Some(entity);
+        });
+        move |scheduler| {
+            scheduler.schedule(move |world: &mut World| {
+                let entity = toast_root.lock().expect("Lock poisoned").take();
+                if let Some(entity) = entity.and_then(|entity| world.get_entity_mut(entity)) {
+                    entity.despawn_recursive();
+                }
+            });
+        }
+    });
+
+    None
+}
+
+#[derive(Props)]
+pub struct ToastHostProps<'a> {
+    corner: Option<&'a str>,
+}
+
+#[allow(non_snake_case)]
+fn ToastList(cx: Scope) -> Element {
+    let toasts = use_resource_expect::<DioxusToasts>(cx);
+    let theme = use_resource_expect::<DioxusTheme>(cx);
+    let corner = use_root_props::<String>(cx).map_or("bottom_right", String::as_str);
+    let align_items = if corner.ends_with("left") {
+        "flex_start"
+    } else {
+        "flex_end"
+    };
+    let panel_color = parse_color(theme.resolve("theme.panel"));
+    let text_color = parse_color(theme.resolve("theme.text"));
+
+    render! {
+        node {
+            flex_direction: "column",
+            align_items: align_items,
+            row_gap: "theme.sm",
+            for toast in &toasts.toasts {
+                ToastCard {
+                    key: "{toast.id}",
+                    message: toast.message.clone(),
+                    opacity: toast.remaining.as_secs_f32() / FADE_OUT.as_secs_f32(),
+                    panel_color: panel_color,
+                    text_color: text_color,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ToastCard(
+    cx: Scope,
+    message: String,
+    opacity: f32,
+    panel_color: Color,
+    text_color: Color,
+) -> Element {
+    let opacity = opacity.min(1.0);
+    render! {
+        node {
+            padding: "theme.md",
+            background_color: UiColor(with_alpha(*panel_color, panel_color.a() * opacity)),
+            text {
+                text: "{message}",
+                text_color: UiColor(with_alpha(*text_color, text_color.a() * opacity)),
+            }
+        }
+    }
+}
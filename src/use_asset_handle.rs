@@ -0,0 +1,19 @@
+use crate::ecs_hooks::EcsContext;
+use bevy::asset::{Asset, AssetServer, Handle};
+use dioxus::core::ScopeState;
+
+/// Loads an asset on the first render and returns the same handle on later renders, only loading
+/// again if `path` changes.
+pub fn use_asset_handle<A: Asset>(cx: &ScopeState, path: &str) -> Handle<A> {
+    let loaded = cx.use_hook(|| None::<(String, Handle<A>)>);
+    match loaded {
+        Some((loaded_path, handle)) if loaded_path == path => handle.clone(),
+        _ => {
+            let handle = EcsContext::get_world_ref(cx)
+                .resource::<AssetServer>()
+                .load(path.to_owned());
+            *loaded = Some((path.to_owned(), handle.clone()));
+            handle
+        }
+    }
+}
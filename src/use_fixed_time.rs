@@ -0,0 +1,44 @@
+use crate::ecs_hooks::use_watched_value;
+use bevy::{
+    ecs::world::World,
+    time::{Fixed, Time},
+};
+use dioxus::core::ScopeState;
+use std::time::Duration;
+
+/// How far Bevy's `FixedUpdate` schedule has advanced since the component mounted.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FixedTime {
+    /// The number of fixed timesteps run.
+    pub steps: u64,
+    /// The fixed time elapsed, `steps` times the timestep.
+    pub elapsed: Duration,
+    /// How far the frame is into the next timestep, from `0.0` to `1.0`, to interpolate between
+    /// the last two steps.
+    pub overstep_fraction: f32,
+}
+
+/// Returns the fixed time elapsed since the component mounted, re-rendering as it advances.
+///
+/// Animations driven by `Time` advance by the frame delta, so they step differently at different
+/// frame rates. Driving them by `steps` or `elapsed` here instead runs them in lockstep with
+/// systems in `FixedUpdate`, e.g. to keep UI in sync with physics, at the cost of moving only once
+/// per step unless interpolated with `overstep_fraction`. Use `Time` for purely visual animations.
+pub fn use_fixed_time(cx: &ScopeState) -> FixedTime {
+    use_watched_value(cx, |_| {
+        let mut start = None;
+        move |world: &World| {
+            let Some(time) = world.get_resource::<Time<Fixed>>() else {
+                return FixedTime::default();
+            };
+            // Don't count the time before the component mounted
+            let start = *start.get_or_insert(time.elapsed());
+            let elapsed = time.elapsed() - start;
+            FixedTime {
+                steps: (elapsed.as_nanos() / time.timestep().as_nanos().max(1)) as u64,
+                elapsed,
+                overstep_fraction: time.overstep_percentage(),
+            }
+        }
+    })
+}
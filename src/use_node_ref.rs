@@ -0,0 +1,124 @@
+use crate::{
+    deferred_system::{use_system_scheduler, DeferredSystemScheduler},
+    pointer_capture::PointerCapture,
+};
+use bevy::{
+    ecs::{
+        bundle::Bundle,
+        entity::Entity,
+        system::{ResMut, Resource},
+        world::World,
+    },
+    utils::HashMap,
+};
+use bevy_mod_picking::pointer::PointerId;
+use dioxus::{hooks::use_on_destroy, prelude::ScopeState};
+use std::{
+    fmt::{self, Display},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static NEXT_NODE_REF_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Resource, Default)]
+pub struct NodeRefs {
+    pub entities: HashMap<u64, Entity>,
+}
+
+/// Returns a reference to the Bevy entity backing an element, attached with
+/// `node_ref: "{node_ref}"`.
+///
+/// Components inserted through [`UseNodeRef::insert`] survive re-renders of the element. The
+/// renderer owns and will overwrite `Style`, `BorderColor`, `Outline`, `BackgroundColor`,
+/// `Transform`, `Visibility`, `ZIndex`, `Text` and `UiImage`, and despawns the entity when the
+/// element is removed or replaced.
+pub fn use_node_ref(cx: &ScopeState) -> UseNodeRef {
+    let scheduler = use_system_scheduler(cx);
+    let node_ref = *cx.use_hook(|| UseNodeRef {
+        id: NEXT_NODE_REF_ID.fetch_add(1, Ordering::Relaxed),
+    });
+    // Scheduled like the cleanups of `use_world_effect`, which still run once the root is torn down
+    use_on_destroy(cx, move || {
+        scheduler.schedule(move |mut node_refs: ResMut<NodeRefs>| {
+            node_refs.entities.remove(&node_ref.id);
+        });
+    });
+
+    node_ref
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UseNodeRef {
+    id: u64,
+}
+
+impl UseNodeRef {
+    pub fn entity(&self, world: &World) -> Option<Entity> {
+        world.resource::<NodeRefs>().entities.get(&self.id).copied()
+    }
+
+    pub fn insert<B: Bundle>(&self, system_scheduler: &DeferredSystemScheduler, bundle: B) {
+        let node_ref = *self;
+        let mut bundle = Some(bundle);
+        system_scheduler.schedule(move |world: &mut World| {
+            let Some(entity) = node_ref.entity(world) else {
+                return;
+            };
+            if let (Some(mut entity), Some(bundle)) = (world.get_entity_mut(entity), bundle.take())
+            {
+                entity.insert(bundle);
+            }
+        });
+    }
+
+    pub fn remove<B: Bundle>(&self, system_scheduler: &DeferredSystemScheduler) {
+        let node_ref = *self;
+        system_scheduler.schedule(move |world: &mut World| {
+            let Some(entity) = node_ref.entity(world) else {
+                return;
+            };
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity.remove::<B>();
+            }
+        });
+    }
+
+    /// Sends `pointer_id`'s `ondrag` and `ondragend` events to this element until every button
+    /// is released, see [`PointerCapture`].
+    pub fn set_pointer_capture(
+        &self,
+        system_scheduler: &DeferredSystemScheduler,
+        pointer_id: PointerId,
+    ) {
+        let node_ref = *self;
+        system_scheduler.schedule(move |world: &mut World| {
+            if let Some(entity) = node_ref.entity(world) {
+                world
+                    .resource_mut::<PointerCapture>()
+                    .set(pointer_id, entity);
+            }
+        });
+    }
+
+    /// Releases `pointer_id` if this element captured it.
+    pub fn release_pointer_capture(
+        &self,
+        system_scheduler: &DeferredSystemScheduler,
+        pointer_id: PointerId,
+    ) {
+        let node_ref = *self;
+        system_scheduler.schedule(move |world: &mut World| {
+            let entity = node_ref.entity(world);
+            let mut pointer_capture = world.resource_mut::<PointerCapture>();
+            if entity.is_some() && pointer_capture.get(pointer_id) == entity {
+                pointer_capture.release(pointer_id);
+            }
+        });
+    }
+}
+
+impl Display for UseNodeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{
+    deferred_system::{use_system_scheduler, DeferredSystemScheduler},
+    ecs_hooks::EcsContext,
+};
+use bevy::{
+    app::AppExit,
+    ecs::{
+        event::EventReader,
+        system::{Res, ResMut, Resource},
+    },
+    log::warn,
+    tasks::{futures_lite::future, IoTaskPool, Task},
+    utils::{HashMap, Instant},
+};
+use dioxus::core::ScopeState;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    cell::{Ref, RefCell},
+    env, fs, io,
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+/// How long a key must go without writes before it's saved, so dragging a volume slider doesn't
+/// write a file every frame.
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Where [`use_persistent`] loads and saves its values, as RON strings.
+pub trait PersistentStorage: Send + Sync + 'static {
+    /// Every saved key and its value, loaded once when the storage is inserted.
+    fn load_all(&self) -> io::Result<HashMap<String, String>>;
+    fn save(&self, key: &str, value: &str) -> io::Result<()>;
+}
+
+/// Stores each key as `<directory>/<key>.ron`, with characters other than ASCII letters, digits,
+/// `_` and `-` percent-encoded so keys like `../settings` stay inside the directory.
+pub struct PersistentFileStorage {
+    pub directory: PathBuf,
+}
+
+impl PersistentFileStorage {
+    /// Stores values in an `app_name` directory in the platform's data directory, e.g.
+    /// `~/.local/share/<app_name>` on Linux, or `None` if it can't be found.
+    pub fn in_data_dir(app_name: &str) -> Option<Self> {
+        let data_dir = if cfg!(target_os = "windows") {
+            PathBuf::from(env::var_os("APPDATA")?)
+        } else if cfg!(target_os = "macos") {
+            PathBuf::from(env::var_os("HOME")?).join("Library/Application Support")
+        } else {
+            match env::var_os("XDG_DATA_HOME") {
+                Some(data_home) => PathBuf::from(data_home),
+                None => PathBuf::from(env::var_os("HOME")?).join(".local/share"),
+            }
+        };
+        Some(Self {
+            directory: data_dir.join(app_name),
+        })
+    }
+}
+
+impl PersistentStorage for PersistentFileStorage {
+    fn load_all(&self) -> io::Result<HashMap<String, String>> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            // Nothing was saved yet
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(error) => return Err(error),
+        };
+        let mut values = HashMap::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(key) = path
+                .file_name()
+                .and_then(|file_name| file_name.to_str()?.strip_suffix(".ron"))
+                .and_then(decode_key)
+            else {
+                continue;
+            };
+            values.insert(key, fs::read_to_string(&path)?);
+        }
+        Ok(values)
+    }
+
+    fn save(&self, key: &str, value: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        let file_name = format!("{}.ron", encode_key(key));
+        fs::write(self.directory.join(file_name), value)
+    }
+}
+
+fn encode_key(key: &str) -> String {
+    let mut encoded = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-' {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn decode_key(encoded: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut encoded_bytes = encoded.bytes();
+    while let Some(byte) = encoded_bytes.next() {
+        if byte != b'%' {
+            bytes.push(byte);
+            continue;
+        }
+        let hex = [encoded_bytes.next()?, encoded_bytes.next()?];
+        bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// The storage backend used by [`use_persistent`], which has no default so values aren't saved
+/// somewhere unexpected. Insert one before the first render, e.g.
+/// `DioxusPersistentStorage::new(PersistentFileStorage::in_data_dir("my_game").unwrap())`.
+#[derive(Resource, Clone)]
+pub struct DioxusPersistentStorage(pub Arc<dyn PersistentStorage>);
+
+impl DioxusPersistentStorage {
+    pub fn new(storage: impl PersistentStorage) -> Self {
+        Self(Arc::new(storage))
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PersistentValues {
+    /// Loaded from storage, or saved to it since.
+    saved: HashMap<String, String>,
+    /// Written, but not saved yet.
+    pending: HashMap<String, (String, Instant)>,
+    saving: Vec<Task<()>>,
+}
+
+/// Loads every saved value when a [`DioxusPersistentStorage`] is inserted, before the components
+/// reading them render, so rendering never waits on storage.
+pub fn load_persistent_values(
+    storage: Res<DioxusPersistentStorage>,
+    mut values: ResMut<PersistentValues>,
+) {
+    match storage.0.load_all() {
+        Ok(saved) => values.saved = saved,
+        Err(error) => warn!("Failed to load bevy_dioxus persistent values: {error}"),
+    }
+}
+
+/// Saves values that haven't been written to for [`SAVE_DEBOUNCE`] on the IO task pool. Before the
+/// app exits, saves every pending value and waits for the saves still running.
+pub fn save_persistent_writes(
+    mut values: ResMut<PersistentValues>,
+    storage: Option<Res<DioxusPersistentStorage>>,
+    mut app_exit: EventReader<AppExit>,
+) {
+    let Some(storage) = storage else {
+        return;
+    };
+    let values = &mut *values;
+    if app_exit.read().count() > 0 {
+        for task in values.saving.drain(..) {
+            future::block_on(task);
+        }
+        for (key, (value, _)) in values.pending.drain() {
+            if let Err(error) = storage.0.save(&key, &value) {
+                warn!("Failed to save bevy_dioxus persistent value `{key}`: {error}");
+            }
+        }
+        return;
+    }
+
+    values.saving.retain(|task| !task.is_finished());
+    let now = Instant::now();
+    let ready = values
+        .pending
+        .iter()
+        .filter(|(_, (_, written_at))| now.duration_since(*written_at) >= SAVE_DEBOUNCE)
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+    for key in ready {
+        let (value, _) = values.pending.remove(&key).unwrap();
+        values.saved.insert(key.clone(), value.clone());
+        let storage = Arc::clone(&storage.0);
+        values.saving.push(IoTaskPool::get().spawn(async move {
+            if let Err(error) = storage.save(&key, &value) {
+                warn!("Failed to save bevy_dioxus persistent value `{key}`: {error}");
+            }
+        }));
+    }
+}
+
+/// State that is loaded from [`DioxusPersistentStorage`] under `key` on the first render, falling
+/// back to `init` if it was never saved or fails to deserialize, and saved whenever it's written.
+/// Panics if no storage was inserted.
+///
+/// Components using the same key don't see each other's writes, so share the state through a
+/// context instead of calling this in several places.
+pub fn use_persistent<T>(cx: &ScopeState, key: &str, init: impl FnOnce() -> T) -> &UsePersistent<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let scheduler = use_system_scheduler(cx);
+    cx.use_hook(|| {
+        let world = EcsContext::get_world_ref(cx);
+        if !world.contains_resource::<DioxusPersistentStorage>() {
+            panic!("Encountered bevy_dioxus use_persistent without a DioxusPersistentStorage.");
+        }
+        // A remounted component must see its own unsaved writes
+        let values = world.resource::<PersistentValues>();
+        let saved = match values.pending.get(key) {
+            Some((value, _)) => Some(value),
+            None => values.saved.get(key),
+        };
+        let value = saved
+            .and_then(|saved| {
+                ron::from_str(saved)
+                    .map_err(|error| {
+                        warn!("Failed to load bevy_dioxus persistent value `{key}`: {error}");
+                    })
+                    .ok()
+            })
+            .unwrap_or_else(init);
+
+        UsePersistent {
+            key: key.to_owned(),
+            value: RefCell::new(value),
+            update: cx.schedule_update(),
+            scheduler,
+        }
+    })
+}
+
+pub struct UsePersistent<T> {
+    key: String,
+    value: RefCell<T>,
+    update: Arc<dyn Fn() + Send + Sync + 'static>,
+    scheduler: DeferredSystemScheduler,
+}
+
+impl<T: Serialize> UsePersistent<T> {
+    pub fn read(&self) -> Ref<'_, T> {
+        self.value.borrow()
+    }
+
+    pub fn write(&self, new_value: T) {
+        match ron::to_string(&new_value) {
+            Ok(serialized) => {
+                let key = self.key.clone();
+                self.scheduler
+                    .schedule(move |mut values: ResMut<PersistentValues>| {
+                        values
+                            .pending
+                            .insert(key.clone(), (serialized.clone(), Instant::now()));
+                    });
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to save bevy_dioxus persistent value `{}`: {error}",
+                    self.key
+                );
+            }
+        }
+        *self.value.borrow_mut() = new_value;
+        (self.update)();
+    }
+}
@@ -0,0 +1,8 @@
+use dioxus::prelude::ScopeState;
+
+/// Returns how many times this component instance has rendered, including the current render.
+pub fn use_render_count(cx: &ScopeState) -> usize {
+    let render_count = cx.use_hook(|| 0);
+    *render_count += 1;
+    *render_count
+}
@@ -0,0 +1,26 @@
+use crate::ecs_hooks::EcsContext;
+use bevy::ecs::component::Component;
+use dioxus::core::ScopeState;
+use std::{any::Any, sync::Arc};
+
+/// Initial data for the `DioxusUiRoot` on the same entity, read with [`use_root_props`], so one
+/// root component can be spawned several times with different configurations.
+#[derive(Component, Clone)]
+pub struct DioxusUiRootProps(Arc<dyn Any + Send + Sync>);
+
+impl DioxusUiRootProps {
+    pub fn new<T: Send + Sync + 'static>(props: T) -> Self {
+        Self(Arc::new(props))
+    }
+}
+
+/// Returns the [`DioxusUiRootProps`] of this component's root, as they were when the component
+/// mounted. Returns `None` if the root has none or they aren't a `T`.
+pub fn use_root_props<T: Send + Sync + 'static>(cx: &ScopeState) -> Option<&T> {
+    cx.use_hook(|| {
+        let props = EcsContext::get_world_ref(cx)
+            .get::<DioxusUiRootProps>(EcsContext::get_root_entity(cx))?;
+        Arc::clone(&props.0).downcast::<T>().ok()
+    })
+    .as_deref()
+}
@@ -0,0 +1,140 @@
+use crate::ecs_hooks::{use_watched_value, EcsContext};
+use bevy::{
+    ecs::world::World,
+    input::{keyboard::KeyCode, Input},
+    render::view::InheritedVisibility,
+};
+use dioxus::core::ScopeState;
+
+/// Calls `callback` when a key chord like `"ctrl+s"` or `"delete"` is pressed, unless the root is
+/// hidden.
+///
+/// Modifiers (`ctrl`, `shift`, `alt`, `super`) must match exactly, so `"s"` doesn't fire for
+/// `"ctrl+s"`.
+pub fn use_shortcut(cx: &ScopeState, shortcut: &str, mut callback: impl FnMut()) {
+    let pressed = use_watched_value(cx, |cx| {
+        let shortcut = Shortcut::parse(shortcut);
+        let root_entity = EcsContext::get_root_entity(cx);
+        move |world: &World| {
+            let root_visible = world
+                .get::<InheritedVisibility>(root_entity)
+                .map_or(true, |visibility| visibility.get());
+            root_visible
+                && world
+                    .get_resource::<Input<KeyCode>>()
+                    .is_some_and(|input| shortcut.just_pressed(input))
+        }
+    });
+
+    if pressed {
+        callback();
+    }
+}
+
+struct Shortcut {
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    super_key: bool,
+    key: KeyCode,
+}
+
+impl Shortcut {
+    fn parse(shortcut: &str) -> Self {
+        let (modifiers, key) = shortcut.rsplit_once('+').unwrap_or(("", shortcut));
+        let modifiers = modifiers
+            .split('+')
+            .filter(|modifier| !modifier.is_empty())
+            .collect::<Vec<_>>();
+        for modifier in &modifiers {
+            if !matches!(*modifier, "ctrl" | "shift" | "alt" | "super") {
+                panic!("Encountered unsupported bevy_dioxus shortcut modifier `{modifier}`.");
+            }
+        }
+
+        Self {
+            ctrl: modifiers.contains(&"ctrl"),
+            shift: modifiers.contains(&"shift"),
+            alt: modifiers.contains(&"alt"),
+            super_key: modifiers.contains(&"super"),
+            key: parse_key_code(key),
+        }
+    }
+
+    fn just_pressed(&self, input: &Input<KeyCode>) -> bool {
+        input.just_pressed(self.key)
+            && input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) == self.ctrl
+            && input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) == self.shift
+            && input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) == self.alt
+            && input.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]) == self.super_key
+    }
+}
+
+fn parse_key_code(key: &str) -> KeyCode {
+    match key {
+        "a" => KeyCode::A,
+        "b" => KeyCode::B,
+        "c" => KeyCode::C,
+        "d" => KeyCode::D,
+        "e" => KeyCode::E,
+        "f" => KeyCode::F,
+        "g" => KeyCode::G,
+        "h" => KeyCode::H,
+        "i" => KeyCode::I,
+        "j" => KeyCode::J,
+        "k" => KeyCode::K,
+        "l" => KeyCode::L,
+        "m" => KeyCode::M,
+        "n" => KeyCode::N,
+        "o" => KeyCode::O,
+        "p" => KeyCode::P,
+        "q" => KeyCode::Q,
+        "r" => KeyCode::R,
+        "s" => KeyCode::S,
+        "t" => KeyCode::T,
+        "u" => KeyCode::U,
+        "v" => KeyCode::V,
+        "w" => KeyCode::W,
+        "x" => KeyCode::X,
+        "y" => KeyCode::Y,
+        "z" => KeyCode::Z,
+        "0" => KeyCode::Key0,
+        "1" => KeyCode::Key1,
+        "2" => KeyCode::Key2,
+        "3" => KeyCode::Key3,
+        "4" => KeyCode::Key4,
+        "5" => KeyCode::Key5,
+        "6" => KeyCode::Key6,
+        "7" => KeyCode::Key7,
+        "8" => KeyCode::Key8,
+        "9" => KeyCode::Key9,
+        "f1" => KeyCode::F1,
+        "f2" => KeyCode::F2,
+        "f3" => KeyCode::F3,
+        "f4" => KeyCode::F4,
+        "f5" => KeyCode::F5,
+        "f6" => KeyCode::F6,
+        "f7" => KeyCode::F7,
+        "f8" => KeyCode::F8,
+        "f9" => KeyCode::F9,
+        "f10" => KeyCode::F10,
+        "f11" => KeyCode::F11,
+        "f12" => KeyCode::F12,
+        "escape" => KeyCode::Escape,
+        "enter" => KeyCode::Return,
+        "space" => KeyCode::Space,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Back,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "page_up" => KeyCode::PageUp,
+        "page_down" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        _ => panic!("Encountered unsupported bevy_dioxus shortcut key `{key}`."),
+    }
+}
@@ -1,6 +1,7 @@
 // https://github.com/DioxusLabs/dioxus-std/blob/8db5b1e8a3b8c81f3174a0c9cb951c87058289ca/std/src/utils/rw/use_rw.rs
 
-use dioxus::prelude::ScopeState;
+use bevy::utils::HashSet;
+use dioxus::{core::ScopeId, hooks::use_on_destroy, prelude::ScopeState};
 use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 pub fn use_state_sendable<T: Send + Sync + 'static>(
@@ -9,14 +10,53 @@ pub fn use_state_sendable<T: Send + Sync + 'static>(
 ) -> &mut UseStateSendable<T> {
     let hook = cx.use_hook(|| UseStateSendable {
         update: cx.schedule_update(),
+        update_any: cx.schedule_update_any(),
+        subscribers: Arc::new(RwLock::new(HashSet::new())),
         value: Arc::new(RwLock::new(init_rw())),
     });
 
     hook
 }
 
+/// Reads a [`UseStateSendable`] provided by an ancestor via `use_context_provider`, and
+/// re-renders this component whenever it is written to.
+pub fn use_context_sendable<T: Send + Sync + 'static>(
+    cx: &ScopeState,
+) -> Option<&UseStateSendable<T>> {
+    let scope_id = cx.scope_id();
+    let hook = cx.use_hook(|| {
+        let state = cx.consume_context::<UseStateSendable<T>>()?;
+        state
+            .subscribers
+            .write()
+            .expect("Lock poisoned")
+            .insert(scope_id);
+        Some(state)
+    });
+
+    let subscribers = hook.as_ref().map(|state| state.subscribers.clone());
+    use_on_destroy(cx, move || {
+        if let Some(subscribers) = subscribers {
+            subscribers
+                .write()
+                .expect("Lock poisoned")
+                .remove(&scope_id);
+        }
+    });
+
+    hook.as_ref()
+}
+
+/// State that can be written from scheduled systems and other threads, re-rendering the owning
+/// component and the subscribers from [`use_context_sendable`].
+///
+/// Writes only mark those components dirty, they render once per tick after every event handler
+/// and scheduled system of the tick ran. Writing several states in one handler, like dioxus'
+/// `use_state`, re-renders each component once with all of them.
 pub struct UseStateSendable<T> {
     update: Arc<dyn Fn() + Send + Sync + 'static>,
+    update_any: Arc<dyn Fn(ScopeId) + Send + Sync + 'static>,
+    subscribers: Arc<RwLock<HashSet<ScopeId>>>,
     value: Arc<RwLock<T>>,
 }
 
@@ -24,6 +64,8 @@ impl<T> Clone for UseStateSendable<T> {
     fn clone(&self) -> Self {
         Self {
             update: self.update.clone(),
+            update_any: self.update_any.clone(),
+            subscribers: self.subscribers.clone(),
             value: self.value.clone(),
         }
     }
@@ -34,13 +76,20 @@ impl<T> UseStateSendable<T> {
         self.value.read().expect("Lock poisoned")
     }
 
+    /// Safe to call after the component owning the state unmounted or its root was despawned, e.g.
+    /// from a system scheduled before then. The value is shared with every clone, and the
+    /// re-render requests go nowhere.
     pub fn write(&self, new_value: T) {
         let mut lock = self.value.write().expect("Lock poisoned");
         *lock = new_value;
+        drop(lock);
         self.needs_update();
     }
 
     pub fn needs_update(&self) {
-        (self.update)()
+        (self.update)();
+        for scope_id in self.subscribers.read().expect("Lock poisoned").iter() {
+            (self.update_any)(*scope_id);
+        }
     }
 }
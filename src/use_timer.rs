@@ -0,0 +1,42 @@
+use crate::ecs_hooks::{use_watched_value, EcsContext};
+use bevy::{
+    ecs::world::World,
+    render::view::InheritedVisibility,
+    time::{Time, Timer, TimerMode},
+};
+use dioxus::core::ScopeState;
+use std::time::Duration;
+
+/// Calls `callback` once `duration` has elapsed, or every `duration` with `TimerMode::Repeating`,
+/// e.g. to dismiss a toast. The timer starts on the first render, ticks against Bevy's `Time`,
+/// and is paused while the root is hidden.
+///
+/// `duration` and `mode` are only read on the first render.
+pub fn use_timer(cx: &ScopeState, duration: Duration, mode: TimerMode, mut callback: impl FnMut()) {
+    let completions = use_watched_value(cx, |cx| {
+        let mut timer = Timer::new(duration, mode);
+        let mut completions = 0;
+        let mut started = false;
+        let root_entity = EcsContext::get_root_entity(cx);
+        move |world: &World| {
+            let root_visible = world
+                .get::<InheritedVisibility>(root_entity)
+                .map_or(true, |visibility| visibility.get());
+            // Don't count the time before the component mounted
+            if started && root_visible {
+                if let Some(time) = world.get_resource::<Time>() {
+                    timer.tick(time.delta());
+                    completions += timer.times_finished_this_tick();
+                }
+            }
+            started = true;
+            completions
+        }
+    });
+
+    let handled_completions = cx.use_hook(|| 0);
+    for _ in *handled_completions..completions {
+        callback();
+    }
+    *handled_completions = completions;
+}
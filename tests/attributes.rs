@@ -0,0 +1,96 @@
+use bevy::{
+    ecs::system::Resource,
+    render::color::Color,
+    text::Text,
+    ui::{Style, Val},
+};
+use bevy_dioxus::prelude::*;
+
+#[derive(Resource)]
+struct Width(&'static str);
+
+#[allow(non_snake_case)]
+fn SizedNode(cx: Scope) -> Element {
+    let width = use_resource::<Width>(cx).map_or("10", |width| width.0);
+
+    render! {
+        node { width: width }
+    }
+}
+
+fn width(app: &DioxusTestApp) -> Val {
+    app.app.world.get::<Style>(app.nodes()[0]).unwrap().width
+}
+
+#[test]
+fn non_finite_numbers_reset_to_the_default() {
+    let mut app = DioxusTestApp::new(SizedNode);
+    assert_eq!(width(&app), Val::Px(10.0));
+
+    for non_finite in ["NaN", "inf", "-inf", "1e999", "NaNpx", "inf%"] {
+        app.world().insert_resource(Width(non_finite));
+        app.update();
+        assert_eq!(width(&app), Val::Auto, "{non_finite}");
+
+        app.world().insert_resource(Width("10"));
+        app.update();
+        assert_eq!(width(&app), Val::Px(10.0));
+    }
+}
+
+#[derive(Resource)]
+struct Highlighted(bool);
+
+#[allow(non_snake_case)]
+fn HighlightedText(cx: Scope) -> Element {
+    let highlighted = use_resource::<Highlighted>(cx).is_some_and(|highlighted| highlighted.0);
+    let color = if highlighted { "#ff0000" } else { "#ffffff" };
+
+    render! {
+        text { text: "label", text_color: color }
+    }
+}
+
+#[test]
+fn color_changes_update_the_text_in_place() {
+    let mut app = DioxusTestApp::new(HighlightedText);
+    let text = app.node_with_text("label").unwrap();
+
+    app.world().insert_resource(Highlighted(true));
+    app.update();
+    assert_eq!(app.node_with_text("label"), Some(text));
+    let text = app.world().get::<Text>(text).unwrap();
+    assert_eq!(text.sections[0].style.color, Color::hex("#ff0000").unwrap());
+}
+
+#[allow(non_snake_case)]
+fn SplitRow(cx: Scope) -> Element {
+    render! {
+        node {
+            flex_direction: "row",
+            node { width: "50%", flex_basis: "25%" }
+            node { width: "50%" }
+        }
+    }
+}
+
+#[test]
+fn percentages_parse_in_a_row() {
+    let app = DioxusTestApp::new(SplitRow);
+    let nodes = app.nodes();
+    let first = app.app.world.get::<Style>(nodes[1]).unwrap();
+    assert_eq!(first.width, Val::Percent(50.0));
+    assert_eq!(first.flex_basis, Val::Percent(25.0));
+    let second = app.app.world.get::<Style>(nodes[2]).unwrap();
+    assert_eq!(second.width, Val::Percent(50.0));
+}
+
+#[test]
+fn malformed_percentages_only_crash_their_root() {
+    let mut app = DioxusTestApp::new(SizedNode);
+    app.world().insert_resource(Width("50%%"));
+    app.update();
+    let texts = app.texts();
+    assert_eq!(texts.len(), 1);
+    assert!(texts[0].starts_with("UI error"), "{texts:?}");
+}
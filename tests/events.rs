@@ -0,0 +1,106 @@
+use bevy_dioxus::prelude::*;
+
+#[allow(non_snake_case)]
+fn Counter(cx: Scope) -> Element {
+    let clicks = use_state(cx, || 0);
+
+    render! {
+        node {
+            onclick: move |_| clicks.modify(|clicks| clicks + 1),
+            text { text: "clicks: {clicks}" }
+        }
+    }
+}
+
+#[test]
+fn click_updates_state() {
+    let mut app = DioxusTestApp::new(Counter);
+    let counter = app.nodes()[0];
+    app.click(counter);
+    app.click(counter);
+    assert_eq!(app.texts(), ["clicks: 2"]);
+}
+
+#[test]
+fn click_bubbles_to_ancestor_listeners() {
+    let mut app = DioxusTestApp::new(Counter);
+    let label = app.node_with_text("clicks: 0").unwrap();
+    app.click(label);
+    assert_eq!(app.texts(), ["clicks: 1"]);
+}
+
+#[allow(non_snake_case)]
+fn Nested(cx: Scope) -> Element {
+    let outer_clicks = use_state(cx, || 0);
+    let inner_clicks = use_state(cx, || 0);
+
+    render! {
+        node {
+            onclick: move |_| outer_clicks.modify(|clicks| clicks + 1),
+            node {
+                onclick: move |_| inner_clicks.modify(|clicks| clicks + 1),
+                text { text: "inner" }
+            }
+            text { text: "{outer_clicks} {inner_clicks}" }
+        }
+    }
+}
+
+#[test]
+fn click_runs_every_listener_up_the_tree() {
+    let mut app = DioxusTestApp::new(Nested);
+    let inner = app.node_with_text("inner").unwrap();
+    app.click(inner);
+    assert_eq!(app.texts(), ["inner", "1 1"]);
+}
+
+#[allow(non_snake_case)]
+fn Hoverable(cx: Scope) -> Element {
+    let hovered = use_state(cx, || false);
+
+    render! {
+        node {
+            onmouse_enter: move |_| hovered.set(true),
+            onmouse_exit: move |_| hovered.set(false),
+            text { text: "hovered: {hovered}" }
+        }
+    }
+}
+
+#[test]
+fn hover_sends_enter_and_exit() {
+    let mut app = DioxusTestApp::new(Hoverable);
+    let node = app.nodes()[0];
+    app.hover(node);
+    assert_eq!(app.texts(), ["hovered: true"]);
+    app.unhover(node);
+    assert_eq!(app.texts(), ["hovered: false"]);
+}
+
+#[allow(non_snake_case)]
+fn StoppedInButton(cx: Scope) -> Element {
+    let outer_clicks = use_state(cx, || 0);
+    let inner_clicks = use_state(cx, || 0);
+
+    render! {
+        node {
+            onclick: move |_| outer_clicks.modify(|clicks| clicks + 1),
+            Button {
+                onclick: move |event: DioxusEvent<PointerButton>| {
+                    event.stop_propagation();
+                    inner_clicks.modify(|clicks| clicks + 1);
+                },
+                text { text: "inner" }
+            }
+            text { text: "{outer_clicks} {inner_clicks}" }
+        }
+    }
+}
+
+#[test]
+fn stop_propagation_in_a_child_component_stops_ancestor_listeners() {
+    let mut app = DioxusTestApp::new(StoppedInButton);
+    let inner = app.node_with_text("inner").unwrap();
+    app.click(inner);
+    assert_eq!(app.texts(), ["inner", "0 1"]);
+}
@@ -0,0 +1,55 @@
+use bevy::{
+    ecs::{entity::Entity, system::Resource},
+    hierarchy::Children,
+    text::Text,
+};
+use bevy_dioxus::prelude::*;
+
+#[derive(Resource)]
+struct ShowMiddle;
+
+#[allow(non_snake_case)]
+fn RootFragment(cx: Scope) -> Element {
+    let show_middle = use_resource::<ShowMiddle>(cx).is_some();
+
+    render! {
+        text { text: "first" }
+        if show_middle {
+            rsx! {
+                text { text: "middle a" }
+                text { text: "middle b" }
+            }
+        }
+        text { text: "last" }
+    }
+}
+
+/// The texts of the root entity's direct children, in order, skipping the empty node dioxus
+/// leaves in place of the hidden fragment.
+fn root_children(app: &DioxusTestApp) -> Vec<String> {
+    let world = &app.app.world;
+    world
+        .get::<Children>(app.root_entity())
+        .map_or(&[][..], |children| &children[..])
+        .iter()
+        .filter_map(|child: &Entity| world.get::<Text>(*child))
+        .map(|text| text.sections[0].value.clone())
+        .collect()
+}
+
+#[test]
+fn root_fragment_spawns_siblings_under_the_root() {
+    let mut app = DioxusTestApp::new(RootFragment);
+    assert_eq!(root_children(&app), ["first", "last"]);
+
+    app.world().insert_resource(ShowMiddle);
+    app.update();
+    assert_eq!(
+        root_children(&app),
+        ["first", "middle a", "middle b", "last"]
+    );
+
+    app.world().remove_resource::<ShowMiddle>();
+    app.update();
+    assert_eq!(root_children(&app), ["first", "last"]);
+}
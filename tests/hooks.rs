@@ -0,0 +1,145 @@
+use bevy::ecs::{
+    component::Component,
+    system::{In, Res, Resource},
+};
+use bevy_dioxus::prelude::*;
+
+#[derive(Component)]
+struct Health(u32);
+
+#[derive(Resource)]
+struct Score(u32);
+
+#[allow(non_snake_case)]
+fn ReadHooks(cx: Scope) -> Element {
+    let healths = use_query::<&Health>(cx);
+    let score = use_resource::<Score>(cx).map_or(0, |score| score.0);
+    let world = use_world(cx);
+    let total_health = healths.query().iter().map(|health| health.0).sum::<u32>();
+    let has_score = world.contains_resource::<Score>();
+
+    render! {
+        text { text: "{total_health} {score} {has_score}" }
+    }
+}
+
+#[test]
+fn read_hooks_compose_in_one_component() {
+    let mut app = DioxusTestApp::new(ReadHooks);
+    assert_eq!(app.texts(), ["0 0 false"]);
+
+    app.world().insert_resource(Score(3));
+    app.world().spawn(Health(2));
+    app.world().spawn(Health(5));
+    app.update();
+    assert_eq!(app.texts(), ["7 3 true"]);
+
+    app.world().resource_mut::<Score>().0 = 4;
+    app.update();
+    assert_eq!(app.texts(), ["7 4 true"]);
+}
+
+#[derive(Resource, PartialEq, Debug)]
+enum MenuState {
+    Main,
+    Settings,
+}
+
+#[allow(non_snake_case)]
+fn Menu(cx: Scope) -> Element {
+    let state = use_resource::<MenuState>(cx);
+    let renders = use_render_count(cx);
+
+    render! {
+        text { text: "{state:?}" }
+        text { text: "{renders}" }
+    }
+}
+
+#[test]
+fn use_resource_only_re_renders_on_change() {
+    let mut app = DioxusTestApp::new(Menu);
+    app.world().insert_resource(MenuState::Main);
+    app.update();
+    app.update();
+    let texts = app.texts();
+    assert_eq!(texts[0], "Some(Main)");
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(app.texts(), texts);
+
+    *app.world().resource_mut::<MenuState>() = MenuState::Settings;
+    app.update();
+    let renders = texts[1].parse::<usize>().unwrap() + 1;
+    assert_eq!(
+        app.texts(),
+        ["Some(Settings)".to_owned(), renders.to_string()]
+    );
+}
+
+#[allow(non_snake_case)]
+fn ThreeStates(cx: Scope) -> Element {
+    let hovered = use_state(cx, || false);
+    let clicked = use_state(cx, || false);
+    let count = use_state_sendable(cx, || 0).clone();
+    let renders = use_render_count(cx);
+    let shown_count = *count.read();
+
+    render! {
+        node {
+            onclick: move |_| {
+                hovered.set(true);
+                clicked.set(true);
+                count.write(*count.read() + 1);
+            },
+            text { text: "{hovered} {clicked} {shown_count}" }
+            text { text: "{renders}" }
+        }
+    }
+}
+
+#[test]
+fn writes_in_one_handler_render_once() {
+    let mut app = DioxusTestApp::new(ThreeStates);
+    let renders = app.texts()[1].parse::<usize>().unwrap();
+
+    let node = app.nodes()[0];
+    app.click(node);
+    assert_eq!(
+        app.texts(),
+        ["true true 1".to_owned(), (renders + 1).to_string()]
+    );
+}
+
+fn add_score(In(bonus): In<u32>, score: Res<Score>) -> u32 {
+    score.0 + bonus
+}
+
+#[allow(non_snake_case)]
+fn ScoreWithBonus(cx: Scope) -> Element {
+    let total = use_state_sendable(cx, || 0).clone();
+    let run_system = use_run_system(cx);
+    let shown_total = *total.read();
+
+    render! {
+        node {
+            onclick: move |_| {
+                let total = total.clone();
+                run_system.run_with_input(add_score, 2, move |sum| total.write(sum));
+            },
+            text { text: "{shown_total}" }
+        }
+    }
+}
+
+#[test]
+fn run_with_input_writes_the_system_output_into_state() {
+    let mut app = DioxusTestApp::new(ScoreWithBonus);
+    app.world().insert_resource(Score(3));
+    let node = app.nodes()[0];
+    app.click(node);
+    app.update();
+    assert_eq!(app.texts(), ["5"]);
+}
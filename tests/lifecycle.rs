@@ -0,0 +1,38 @@
+use bevy::{
+    ecs::{system::Resource, world::World},
+    hierarchy::DespawnRecursiveExt,
+};
+use bevy_dioxus::prelude::*;
+
+#[derive(Resource)]
+struct ClosureRan;
+
+#[allow(non_snake_case)]
+fn PendingWrite(cx: Scope) -> Element {
+    let state = use_state_sendable(cx, || 0);
+    let scheduler = use_system_scheduler(cx);
+    // Scheduled while rendering, so it's still pending until the next tick
+    cx.use_hook(|| {
+        let state = state.clone();
+        scheduler.schedule(move |world: &mut World| {
+            state.write(1);
+            world.insert_resource(ClosureRan);
+        });
+    });
+
+    render! {
+        text { text: "{state.read()}" }
+    }
+}
+
+#[test]
+fn despawning_a_root_drops_its_pending_closures() {
+    let mut app = DioxusTestApp::new(PendingWrite);
+    assert_eq!(app.texts(), ["0"]);
+
+    let root_entity = app.root_entity();
+    app.world().entity_mut(root_entity).despawn_recursive();
+    app.update();
+    app.update();
+    assert!(!app.world().contains_resource::<ClosureRan>());
+}
@@ -0,0 +1,49 @@
+use bevy::ecs::system::Resource;
+use bevy_dioxus::{prelude::*, DioxusUiRoot};
+
+#[derive(Resource)]
+struct Broken;
+
+#[derive(Resource)]
+struct Label(&'static str);
+
+#[allow(non_snake_case)]
+fn Fragile(cx: Scope) -> Element {
+    if use_resource::<Broken>(cx).is_some() {
+        panic!("broken");
+    }
+
+    render! {
+        text { text: "fragile" }
+    }
+}
+
+#[allow(non_snake_case)]
+fn App(cx: Scope) -> Element {
+    let label = use_resource::<Label>(cx).map_or("sibling", |label| label.0);
+
+    render! {
+        text { text: label }
+        PanicBoundary { root: DioxusUiRoot(Fragile), fallback: "fallback" }
+    }
+}
+
+#[test]
+fn panic_boundary_only_replaces_its_root() {
+    let mut app = DioxusTestApp::new(App);
+    // The nested root spawns, then renders
+    app.update();
+    app.update();
+    assert_eq!(app.texts(), ["sibling", "fragile"]);
+
+    app.world().insert_resource(Broken);
+    app.update();
+    assert_eq!(app.texts(), ["sibling", "fallback"]);
+
+    // The boundary's root stays crashed, while the rest keeps rendering
+    app.world().remove_resource::<Broken>();
+    app.world().insert_resource(Label("updated sibling"));
+    app.update();
+    app.update();
+    assert_eq!(app.texts(), ["updated sibling", "fallback"]);
+}
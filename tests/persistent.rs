@@ -0,0 +1,134 @@
+use bevy::{app::AppExit, utils::HashMap};
+use bevy_dioxus::prelude::*;
+use std::{
+    env, fs, io,
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// A directory no other test, or other run of this test binary, uses.
+fn unique_temp_dir() -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    env::temp_dir().join(format!("bevy_dioxus_persistent_{}_{count}", process::id()))
+}
+
+#[test]
+fn file_storage_keeps_keys_inside_its_directory() {
+    let root = unique_temp_dir();
+    let storage = PersistentFileStorage {
+        directory: root.join("saves"),
+    };
+
+    storage.save("../escaped", "1").unwrap();
+    storage.save("volume", "0.5").unwrap();
+    assert!(!root.join("escaped.ron").exists());
+    assert_eq!(fs::read_dir(root.join("saves")).unwrap().count(), 2);
+
+    let values = storage.load_all().unwrap();
+    assert_eq!(values.get("../escaped").map(String::as_str), Some("1"));
+    assert_eq!(values.get("volume").map(String::as_str), Some("0.5"));
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[derive(Clone, Default)]
+struct MemoryStorage(Arc<Mutex<HashMap<String, String>>>);
+
+impl MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+}
+
+impl PersistentStorage for MemoryStorage {
+    fn load_all(&self) -> io::Result<HashMap<String, String>> {
+        Ok(self.0.lock().unwrap().clone())
+    }
+
+    fn save(&self, key: &str, value: &str) -> io::Result<()> {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(key.to_owned(), value.to_owned());
+        Ok(())
+    }
+}
+
+/// Waits to render `Volume` until a storage is inserted, as `use_persistent` needs one.
+#[allow(non_snake_case)]
+fn Settings(cx: Scope) -> Element {
+    let has_storage = use_resource::<DioxusPersistentStorage>(cx).is_some();
+
+    render! {
+        if has_storage {
+            rsx! { Volume {} }
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn Volume(cx: Scope) -> Element {
+    let volume = use_persistent(cx, "volume", || 5);
+
+    render! {
+        node {
+            onclick: move |_| volume.write(*volume.read() + 1),
+            text { text: "{volume.read()}" }
+        }
+    }
+}
+
+fn settings_app(storage: &MemoryStorage) -> DioxusTestApp {
+    let mut app = DioxusTestApp::new(Settings);
+    app.world()
+        .insert_resource(DioxusPersistentStorage::new(storage.clone()));
+    app.update();
+    app
+}
+
+#[test]
+fn use_persistent_debounces_saves() {
+    let storage = MemoryStorage::default();
+    storage.save("volume", "7").unwrap();
+    let mut app = settings_app(&storage);
+    assert_eq!(app.texts(), ["7"]);
+
+    let node = app.nodes()[0];
+    app.click(node);
+    app.update();
+    assert_eq!(app.texts(), ["8"]);
+    assert_eq!(storage.get("volume").as_deref(), Some("7"));
+
+    thread::sleep(Duration::from_millis(600));
+    // Saved on the IO task pool, so give the save a moment to finish
+    for _ in 0..100 {
+        app.update();
+        if storage.get("volume").as_deref() == Some("8") {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(storage.get("volume").as_deref(), Some("8"));
+}
+
+#[test]
+fn use_persistent_saves_pending_writes_on_app_exit() {
+    let storage = MemoryStorage::default();
+    let mut app = settings_app(&storage);
+    assert_eq!(app.texts(), ["5"]);
+
+    let node = app.nodes()[0];
+    app.click(node);
+    app.update();
+    assert_eq!(storage.get("volume"), None);
+
+    app.world().send_event(AppExit);
+    app.update();
+    assert_eq!(storage.get("volume").as_deref(), Some("6"));
+}
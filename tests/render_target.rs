@@ -0,0 +1,44 @@
+use bevy::{
+    asset::{AssetApp, Assets},
+    hierarchy::DespawnRecursiveExt,
+    render::{
+        camera::{Camera, RenderTarget},
+        texture::Image,
+    },
+};
+use bevy_dioxus::prelude::*;
+
+#[allow(non_snake_case)]
+fn Screen(cx: Scope) -> Element {
+    render! {
+        text { text: "screen" }
+    }
+}
+
+fn camera_targets(app: &mut DioxusTestApp) -> Vec<RenderTarget> {
+    app.world()
+        .query::<&Camera>()
+        .iter(&app.app.world)
+        .map(|camera| camera.target.clone())
+        .collect()
+}
+
+#[test]
+fn render_target_camera_lives_as_long_as_its_root() {
+    let mut app = DioxusTestApp::new(Screen);
+    app.app.init_asset::<Image>();
+    let image = app
+        .world()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+    let root_entity = app
+        .world()
+        .spawn(DioxusUiBundle::new(Screen).with_render_target(image.clone()))
+        .id();
+    app.update();
+    assert_eq!(camera_targets(&mut app), [RenderTarget::Image(image)]);
+
+    app.world().entity_mut(root_entity).despawn_recursive();
+    app.update();
+    assert!(camera_targets(&mut app).is_empty());
+}
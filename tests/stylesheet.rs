@@ -0,0 +1,41 @@
+use bevy::{
+    asset::Assets,
+    ui::{Style, UiRect, Val},
+    utils::HashMap,
+};
+use bevy_dioxus::prelude::*;
+
+#[allow(non_snake_case)]
+fn ClassedNode(cx: Scope) -> Element {
+    let class = "spaced";
+    render! {
+        node {
+            class: "{class}",
+            column_gap: "4",
+        }
+    }
+}
+
+#[test]
+fn inline_attributes_override_classes() {
+    let mut app = DioxusTestApp::new(ClassedNode);
+    let stylesheet = app
+        .world()
+        .resource_mut::<Assets<DioxusStylesheet>>()
+        .add(DioxusStylesheet {
+            classes: HashMap::from_iter([(
+                "spaced".to_owned(),
+                vec![
+                    ("column_gap".to_owned(), "20".to_owned()),
+                    ("padding".to_owned(), "8".to_owned()),
+                ],
+            )]),
+        });
+    app.world().insert_resource(DioxusUiStylesheet(stylesheet));
+    app.update();
+
+    let node = app.nodes()[0];
+    let style = app.world().get::<Style>(node).unwrap();
+    assert_eq!(style.column_gap, Val::Px(4.0));
+    assert_eq!(style.padding, UiRect::all(Val::Px(8.0)));
+}